@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// maps of characters, parsed with Grid::parse
+const DAYS: &[u8] = &[3, 10, 11, 13, 14, 16, 17, 21, 23];
+
+fuzz_target!(|input: &str| aoc::fuzz_parsers(2023, DAYS, input));
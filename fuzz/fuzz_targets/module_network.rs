@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// flip-flops and conjunctions sending pulses to each other
+const DAYS: &[u8] = &[20];
+
+fuzz_target!(|input: &str| aoc::fuzz_parsers(2023, DAYS, input));
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// seeds and the range maps between them
+const DAYS: &[u8] = &[5];
+
+fuzz_target!(|input: &str| aoc::fuzz_parsers(2023, DAYS, input));
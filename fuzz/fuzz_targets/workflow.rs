@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// workflows of rules followed by parts
+const DAYS: &[u8] = &[19];
+
+fuzz_target!(|input: &str| aoc::fuzz_parsers(2023, DAYS, input));
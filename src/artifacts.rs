@@ -0,0 +1,224 @@
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+
+use crate::util::image::{Image, ImageFormat};
+
+/// directory that `--emit` writes to unless `--output-dir` says otherwise
+pub const DEFAULT_OUTPUT_DIR: &str = "output";
+/// name of the file in the output dir listing what the last run wrote
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// an output of a solver besides the answer, like a map of the solved puzzle or a trace of a simulation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Artifact {
+    /// file name in the day's directory, the extension tells what kind of file it is
+    /// images have none, they get the one of the format they're written in
+    pub name: String,
+    pub content: Content,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Content {
+    Bytes(Vec<u8>),
+    /// encoded when written, so it's only encoded once and in the format the run asks for
+    Image(Image),
+}
+
+impl Artifact {
+    pub fn text(name: &str, text: impl Into<String>) -> Artifact {
+        Artifact::bytes(name, text.into().into_bytes())
+    }
+
+    pub fn bytes(name: &str, content: Vec<u8>) -> Artifact {
+        Artifact {
+            name: name.to_string(),
+            content: Content::Bytes(content),
+        }
+    }
+
+    pub fn image(name: &str, image: Image) -> Artifact {
+        Artifact {
+            name: name.to_string(),
+            content: Content::Image(image),
+        }
+    }
+
+    /// the file name and content as written, images encoded in `image_format`
+    pub fn encode(&self, image_format: ImageFormat) -> (String, Cow<'_, [u8]>) {
+        match &self.content {
+            Content::Bytes(bytes) => (self.name.clone(), Cow::Borrowed(bytes)),
+            Content::Image(image) => (
+                format!("{}.{}", self.name, image_format.extension()),
+                Cow::Owned(image_format.encode(image)),
+            ),
+        }
+    }
+}
+
+/// one written artifact as listed in the manifest
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    /// relative to the output dir
+    pub path: String,
+    pub bytes: usize,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Manifest {
+    /// seconds since the unix epoch
+    pub time: u64,
+    pub artifacts: Vec<Entry>,
+}
+
+/// writes the artifacts of one run into the output dir, each day into `<year>/dayNN/`
+pub struct Emitter {
+    dir: PathBuf,
+    image_format: ImageFormat,
+    entries: Vec<Entry>,
+}
+
+impl Emitter {
+    pub fn new(dir: &Path, image_format: ImageFormat) -> Emitter {
+        Emitter {
+            dir: dir.to_path_buf(),
+            image_format,
+            entries: vec![],
+        }
+    }
+
+    /// days without artifacts don't get a directory
+    pub fn write(&mut self, year: u16, day: u8, artifacts: &[Artifact]) -> Result<(), String> {
+        if artifacts.is_empty() {
+            return Ok(());
+        }
+        let day_dir = format!("{year}/day{day:02}");
+        fs::create_dir_all(self.dir.join(&day_dir)).map_err(|err| {
+            format!(
+                "could not create {}: {err}",
+                self.dir.join(&day_dir).display()
+            )
+        })?;
+
+        for artifact in artifacts {
+            let (name, content) = artifact.encode(self.image_format);
+            // solvers choose the names, they must not write anywhere else
+            if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+                return Err(format!(
+                    "day {day} emitted an artifact with the bad name {name:?}"
+                ));
+            }
+
+            let path = format!("{day_dir}/{name}");
+            fs::write(self.dir.join(&path), &content).map_err(|err| {
+                format!("could not write {}: {err}", self.dir.join(&path).display())
+            })?;
+            self.entries.push(Entry {
+                year,
+                day,
+                name,
+                path,
+                bytes: content.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// writes the manifest of everything written by this run, replacing the one of the run before
+    pub fn finish(self) -> Result<(PathBuf, Vec<Entry>), String> {
+        let manifest = Manifest {
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_secs()),
+            artifacts: self.entries,
+        };
+
+        fs::create_dir_all(&self.dir)
+            .map_err(|err| format!("could not create {}: {err}", self.dir.display()))?;
+        let path = self.dir.join(MANIFEST_FILE);
+        fs::write(
+            &path,
+            serde_json::to_string_pretty(&manifest).unwrap() + "\n",
+        )
+        .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        Ok((path, manifest.artifacts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::grid::Grid;
+    use crate::util::image::{BLACK, WHITE};
+
+    #[test]
+    fn writes_artifacts_and_manifest() {
+        let dir = std::env::temp_dir().join(format!("aoc-artifacts-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut emitter = Emitter::new(&dir, ImageFormat::Png);
+        emitter
+            .write(2023, 7, &[Artifact::text("map.txt", "#.#\n")])
+            .unwrap();
+        emitter
+            .write(2023, 12, &[Artifact::bytes("trace.bin", vec![1, 2, 3])])
+            .unwrap();
+        emitter.write(2023, 13, &[]).unwrap();
+        let (manifest, entries) = emitter.finish().unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("2023/day07/map.txt")).unwrap(),
+            "#.#\n"
+        );
+        assert_eq!(
+            fs::read(dir.join("2023/day12/trace.bin")).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(!dir.join("2023/day13").exists());
+        let paths: Vec<&str> = entries.iter().map(|entry| entry.path.as_str()).collect();
+        assert_eq!(paths, vec!["2023/day07/map.txt", "2023/day12/trace.bin"]);
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(manifest).unwrap()).unwrap();
+        assert_eq!(written["artifacts"][1]["bytes"], 3);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn writes_images_in_the_chosen_format() {
+        let dir = std::env::temp_dir().join(format!("aoc-artifacts-images-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let grid = Grid::new(2, 1, vec![true, false]);
+        let image = Image::render(&grid, 1, |&white| if white { WHITE } else { BLACK });
+        let mut emitter = Emitter::new(&dir, ImageFormat::Ppm);
+        emitter
+            .write(2023, 10, &[image.clone().artifact("loop")])
+            .unwrap();
+        let (_, entries) = emitter.finish().unwrap();
+
+        assert_eq!(entries[0].path, "2023/day10/loop.ppm");
+        assert_eq!(
+            fs::read(dir.join("2023/day10/loop.ppm")).unwrap(),
+            image.ppm()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_names_outside_the_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-artifacts-names-{}", std::process::id()));
+        let mut emitter = Emitter::new(&dir, ImageFormat::Png);
+        for name in ["../escape.txt", "sub/file.txt", "", ".hidden"] {
+            assert!(emitter.write(2023, 1, &[Artifact::text(name, "")]).is_err());
+        }
+        let _ = fs::remove_dir_all(dir);
+    }
+}
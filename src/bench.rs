@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::runner::catch_panic;
+use crate::{Answer, AocError, DynSolver, LEGACY_YEAR};
+
+/// the separately timed stages of solving a day
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Stage::Parse => "parse",
+            Stage::PartOne => "part1",
+            Stage::PartTwo => "part2",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Stage {
+    fn from_name(name: &str) -> Option<Stage> {
+        match name {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::PartOne),
+            "part2" => Some(Stage::PartTwo),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// samples don't need to be sorted, but there must be at least one
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        samples.sort();
+        // nearest rank percentiles
+        let rank = |percent: usize| samples[(samples.len() * percent).div_ceil(100).max(1) - 1];
+        Stats {
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+/// timing results of one day, keyed by stage
+pub type DayTimings = BTreeMap<Stage, Stats>;
+
+/// times parsing and both parts separately, each stage is run `warmup` times untimed and then `iterations` times timed
+/// parts that have no puzzle (day 25 part 2) are left out
+pub fn bench_day(
+    day: u8,
+    solver: &dyn DynSolver,
+    input_str: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayTimings, AocError> {
+    catch_panic(day, || {
+        let mut timings = DayTimings::new();
+
+        // solve everything once first, so failing days are reported instead of timed
+        let input = solver.parse(input_str)?;
+        let part_one = solver.part_one(input.as_ref())?;
+        let part_two = solver.part_two(input.as_ref())?;
+
+        let parse = || solver.parse(input_str);
+        timings.insert(Stage::Parse, measure(parse, warmup, iterations));
+
+        if part_one != Answer::None {
+            let part_one = || solver.part_one(input.as_ref());
+            timings.insert(Stage::PartOne, measure(part_one, warmup, iterations));
+        }
+        if part_two != Answer::None {
+            let part_two = || solver.part_two(input.as_ref());
+            timings.insert(Stage::PartTwo, measure(part_two, warmup, iterations));
+        }
+
+        Ok(timings)
+    })
+}
+
+fn measure<T>(mut stage: impl FnMut() -> T, warmup: usize, iterations: usize) -> Stats {
+    for _ in 0..warmup {
+        stage();
+    }
+
+    let mut samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            let result = stage();
+            let elapsed = start.elapsed();
+            drop(result); // don't time dropping the result
+            elapsed
+        })
+        .collect();
+
+    Stats::from_samples(&mut samples)
+}
+
+/// saves results as csv (year,day,stage,min_ns,median_ns,p95_ns) so later runs can compare against them
+pub fn save(results: &BTreeMap<(u16, u8), DayTimings>, path: &Path) -> Result<(), String> {
+    let mut csv = "year,day,stage,min_ns,median_ns,p95_ns\n".to_string();
+
+    for ((year, day), timings) in results.iter() {
+        for (stage, stats) in timings.iter() {
+            csv += &format!(
+                "{year},{day},{stage},{},{},{}\n",
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos()
+            );
+        }
+    }
+
+    fs::write(path, csv).map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+/// loads results previously written by `save`
+/// (files from before the year column was added are read as `LEGACY_YEAR`)
+pub fn load(path: &Path) -> Result<BTreeMap<(u16, u8), DayTimings>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    let mut results: BTreeMap<(u16, u8), DayTimings> = BTreeMap::new();
+    let has_year = content.starts_with("year,");
+
+    for (line_nr, line) in content.lines().enumerate().skip(1) {
+        let mut fields: Vec<&str> = line.split(',').collect();
+        let invalid = || format!("{}:{}: invalid line {line:?}", path.display(), line_nr + 1);
+
+        let year = if has_year {
+            fields.remove(0).parse().map_err(|_| invalid())?
+        } else {
+            LEGACY_YEAR
+        };
+        if fields.len() != 5 {
+            return Err(invalid());
+        }
+        let day: u8 = fields[0].parse().map_err(|_| invalid())?;
+        let stage = Stage::from_name(fields[1]).ok_or_else(invalid)?;
+        let nanos = fields[2..]
+            .iter()
+            .map(|field| field.parse().map(Duration::from_nanos))
+            .collect::<Result<Vec<Duration>, _>>()
+            .map_err(|_| invalid())?;
+
+        results.entry((year, day)).or_default().insert(
+            stage,
+            Stats {
+                min: nanos[0],
+                median: nanos[1],
+                p95: nanos[2],
+            },
+        );
+    }
+
+    Ok(results)
+}
+
+/// relative change of the median in percent, positive means slower than the baseline
+pub fn median_change(baseline: &Stats, current: &Stats) -> f64 {
+    let baseline = baseline.median.as_secs_f64();
+    let current = current.median.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current - baseline) / baseline * 100.0
+}
+
+/// whether the median got more than `threshold` percent slower than the baseline
+pub fn is_regression(baseline: &Stats, current: &Stats, threshold: f64) -> bool {
+    median_change(baseline, current) > threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    fn stats(min: u64, median: u64, p95: u64) -> Stats {
+        Stats {
+            min: Duration::from_millis(min),
+            median: Duration::from_millis(median),
+            p95: Duration::from_millis(p95),
+        }
+    }
+
+    #[test]
+    fn nearest_rank_percentiles() {
+        assert_eq!(Stats::from_samples(&mut millis(&[5])), stats(5, 5, 5));
+        assert_eq!(Stats::from_samples(&mut millis(&[3, 1])), stats(1, 1, 3));
+        let mut twenty: Vec<u64> = (1..=20).rev().collect();
+        assert_eq!(Stats::from_samples(&mut millis(&twenty)), stats(1, 10, 19));
+        twenty.push(21);
+        assert_eq!(Stats::from_samples(&mut millis(&twenty)), stats(1, 11, 20));
+    }
+
+    #[test]
+    fn saved_results_load_again() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.csv", std::process::id()));
+        let results = BTreeMap::from([
+            (
+                (2023, 1),
+                DayTimings::from([
+                    (Stage::Parse, stats(1, 2, 3)),
+                    (Stage::PartTwo, stats(4, 5, 6)),
+                ]),
+            ),
+            (
+                (2024, 25),
+                DayTimings::from([(Stage::PartOne, stats(7, 8, 9))]),
+            ),
+        ]);
+        save(&results, &path).unwrap();
+        assert_eq!(load(&path).unwrap(), results);
+
+        fs::write(&path, "day,stage,min_ns,median_ns,p95_ns\n7,part2,1,2,3\n").unwrap();
+        let legacy = load(&path).unwrap();
+        assert_eq!(
+            legacy[&(LEGACY_YEAR, 7)][&Stage::PartTwo],
+            Stats {
+                min: Duration::from_nanos(1),
+                median: Duration::from_nanos(2),
+                p95: Duration::from_nanos(3),
+            }
+        );
+
+        fs::write(
+            &path,
+            "year,day,stage,min_ns,median_ns,p95_ns\n2023,7,part3,1,2,3\n",
+        )
+        .unwrap();
+        assert!(load(&path).is_err());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn flags_slowdowns_above_the_threshold() {
+        let baseline = stats(1, 100, 200);
+        assert!((median_change(&baseline, &stats(1, 125, 200)) - 25.0).abs() < 1e-9);
+        assert!(is_regression(&baseline, &stats(1, 111, 200), 10.0));
+        assert!(!is_regression(&baseline, &stats(1, 109, 200), 10.0));
+        assert!(!is_regression(&baseline, &stats(1, 50, 200), 10.0));
+    }
+}
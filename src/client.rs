@@ -0,0 +1,545 @@
+use std::env;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// environment variable holding the session cookie, takes precedence over the config file
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// environment variable overriding the base url, mainly for testing against a local server
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// environment variable pointing to the config file
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+/// default location of the config file, relative to the working directory
+pub const DEFAULT_CONFIG_FILE: &str = "aoc.toml";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_MIN_INTERVAL: f64 = 5.0;
+/// file in the cache dir remembering when the last request was sent
+const LAST_REQUEST_FILE: &str = ".last_request";
+
+/// settings for talking to the website, read from a file like
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "http://localhost:8080" # optional
+/// min_interval = 5.0                 # optional, seconds between requests
+/// ```
+#[derive(Deserialize, Default, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub min_interval: Option<f64>,
+}
+
+impl Config {
+    /// reads `$AOC_CONFIG` or `aoc.toml` (which don't have to exist) and applies the environment variables on top
+    pub fn load() -> Result<Config, String> {
+        let path = env::var_os(CONFIG_VAR).unwrap_or_else(|| DEFAULT_CONFIG_FILE.into());
+        let path = Path::new(&path);
+
+        let mut config = if path.is_file() {
+            let content = fs::read_to_string(path)
+                .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+            Config::parse(&content).map_err(|err| format!("{}: {err}", path.display()))?
+        } else {
+            Config::default()
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = Some(base_url);
+        }
+
+        Ok(config)
+    }
+
+    pub fn parse(content: &str) -> Result<Config, String> {
+        toml::from_str(content).map_err(|err| format!("{err}"))
+    }
+}
+
+/// client for the puzzle website, sends at most one request per `min_interval`
+/// (also across runs, the time of the last request is kept in the cache dir)
+pub struct Client {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    cache_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: &Config, cache_dir: &Path) -> Result<Client, String> {
+        let session = config
+            .session
+            .as_ref()
+            .map(|session| session.trim())
+            .ok_or(format!(
+                "no session token, set {SESSION_VAR} or session in {DEFAULT_CONFIG_FILE}"
+            ))?;
+        let min_interval = config.min_interval.unwrap_or(DEFAULT_MIN_INTERVAL);
+        if !(0.0..=3600.0).contains(&min_interval) {
+            return Err(format!(
+                "min_interval must be between 0 and 3600 seconds, not {min_interval}"
+            ));
+        }
+
+        Ok(Client {
+            base_url: config
+                .base_url
+                .as_deref()
+                .unwrap_or(DEFAULT_BASE_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            session: session.to_string(),
+            min_interval: Duration::from_secs_f64(min_interval),
+            cache_dir: cache_dir.to_path_buf(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        })
+    }
+
+    /// downloads the input of a day, or returns the cached copy if there is one (unless `force` is set)
+    pub fn fetch_input(&self, year: u16, day: u8, force: bool) -> Result<Fetched, String> {
+        let path = self
+            .cache_dir
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"));
+        if path.is_file() && !force {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.get(&format!("/{year}/day/{day}/input"))?;
+        if input.is_empty() {
+            return Err(format!("the input for {year} day {day} is empty"));
+        }
+
+        write_private(&path, &input)?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// posts an answer and reports what the site thought of it
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, String> {
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let page = self.send(&format!("/{year}/day/{day}/answer"), Some(&form))?;
+        Ok(Outcome::parse(&page))
+    }
+
+    fn get(&self, path: &str) -> Result<String, String> {
+        self.send(path, None)
+    }
+
+    /// GET without a form, POST with one
+    fn send(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String, String> {
+        self.wait_for_turn()?;
+
+        let url = format!("{}{path}", self.base_url);
+        let cookie = format!("session={}", self.session);
+        let response = match form {
+            Some(form) => self.agent.post(&url).set("Cookie", &cookie).send_form(form),
+            None => self.agent.get(&url).set("Cookie", &cookie).call(),
+        };
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| format!("could not read response from {url}: {err}")),
+            Err(ureq::Error::Status(404, _)) => {
+                Err(format!("{url} not found, is the puzzle unlocked yet?"))
+            }
+            Err(ureq::Error::Status(400, _)) => Err(format!(
+                "{url} rejected the session token, it may have expired"
+            )),
+            Err(ureq::Error::Status(code, _)) => Err(format!("{url} answered with status {code}")),
+            Err(err) => Err(format!("request to {url} failed: {err}")),
+        }
+    }
+
+    /// sleeps until `min_interval` has passed since the last request, then records this one
+    /// the time is kept in nanoseconds, anything coarser could make the wait a little too short
+    fn wait_for_turn(&self) -> Result<(), String> {
+        create_private_dir(&self.cache_dir)?;
+        let stamp = self.cache_dir.join(LAST_REQUEST_FILE);
+
+        let last_request = last_request(&stamp);
+        if let Some(last_request) = last_request {
+            let next_request = last_request + self.min_interval;
+            let now = since_epoch();
+            if next_request > now {
+                thread::sleep(next_request - now);
+            }
+        }
+
+        fs::write(&stamp, since_epoch().as_nanos().to_string())
+            .map_err(|err| format!("could not write {}: {err}", stamp.display()))
+    }
+}
+
+/// time of the last request since the unix epoch, None if there was none yet
+fn last_request(stamp: &Path) -> Option<Duration> {
+    fs::read_to_string(stamp)
+        .ok()
+        .and_then(|nanos| nanos.trim().parse().ok())
+        .map(Duration::from_nanos)
+}
+
+/// where `fetch_input` got the input from
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// what the site said about a submitted answer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint in which direction
+    Wrong,
+    /// submitted too soon after the previous answer, nothing was checked
+    Wait {
+        seconds: u64,
+    },
+    /// the part is already solved or not unlocked yet
+    WrongLevel,
+    /// anything else, with the text of the message
+    Unknown(String),
+}
+
+impl Outcome {
+    /// reads the message out of the page returned after submitting
+    pub fn parse(page: &str) -> Outcome {
+        let message = message_text(page);
+
+        if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Outcome::TooHigh
+            } else if message.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::Wait {
+                seconds: wait_seconds(&message).unwrap_or(60),
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown(message)
+        }
+    }
+
+    /// whether the answer was checked and found wrong
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait { seconds } => write!(f, "answered too recently, wait {seconds}s"),
+            Outcome::WrongLevel => write!(f, "part already solved or still locked"),
+            Outcome::Unknown(message) => write!(f, "unexpected response: {message}"),
+        }
+    }
+}
+
+/// text of the `<article>` holding the message (the whole page if there is none), without tags
+fn message_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article.split_once('>').map_or(article, |(_, text)| text))
+        .unwrap_or(page);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// parses "You have 1m 5s left to wait" into 65
+fn wait_seconds(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (left, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for amount in left.split_whitespace() {
+        let unit = amount.chars().last()?;
+        let value: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+        seconds += match unit {
+            'h' => value * 3600,
+            'm' => value * 60,
+            's' => value,
+            _ => return None,
+        };
+    }
+    Some(seconds)
+}
+
+fn since_epoch() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// inputs are personal, so the cache is only readable by the current user
+fn create_private_dir(dir: &Path) -> Result<(), String> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder
+        .create(dir)
+        .map_err(|err| format!("could not create {}: {err}", dir.display()))
+}
+
+/// writes to a temporary file first, so an interrupted download never leaves a partial input behind
+fn write_private(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        create_private_dir(dir)?;
+    }
+    let partial = path.with_extension("part");
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let write = |file: &mut File| file.write_all(content.as_bytes());
+    options
+        .open(&partial)
+        .and_then(|mut file| write(&mut file))
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// minimal http server answering `responses` in order, sends every request it received back through the channel
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                // request line and headers, then the body if there is one
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request += &line;
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request += &String::from_utf8(body_bytes).unwrap();
+                sender.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn config(base_url: &str, min_interval: f64) -> Config {
+        Config {
+            session: Some("secret".to_string()),
+            base_url: Some(base_url.to_string()),
+            min_interval: Some(min_interval),
+        }
+    }
+
+    #[test]
+    fn downloads_and_caches_input() {
+        let (base_url, requests) = stub_server(vec![(200, "1 2 3\n")]);
+        let cache = temp_dir("fetch");
+        let client = Client::new(&config(&base_url, 0.0), &cache).unwrap();
+
+        let path = cache.join("2023").join("day07.txt");
+        assert_eq!(
+            client.fetch_input(2023, 7, false),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/7/input "));
+        assert!(request.contains("session=secret"));
+
+        // second fetch doesn't hit the server (which would fail, it only answers once)
+        assert_eq!(
+            client.fetch_input(2023, 7, false),
+            Ok(Fetched::Cached(path.clone()))
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(&cache), 0o700);
+            assert_eq!(mode(&path), 0o600);
+        }
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn reports_locked_puzzles() {
+        let (base_url, _requests) = stub_server(vec![(404, "Not Found")]);
+        let cache = temp_dir("locked");
+        let client = Client::new(&config(&base_url, 0.0), &cache).unwrap();
+
+        let err = client.fetch_input(2023, 25, false).unwrap_err();
+        assert!(err.contains("unlocked"), "{err}");
+        assert!(!cache.join("2023").join("day25.txt").exists());
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let (base_url, _requests) = stub_server(vec![(200, "a\n"), (200, "b\n")]);
+        let cache = temp_dir("interval");
+        let client = Client::new(&config(&base_url, 0.3), &cache).unwrap();
+
+        let stamp = cache.join(LAST_REQUEST_FILE);
+        client.fetch_input(2023, 1, false).unwrap();
+        let first = last_request(&stamp).unwrap();
+        client.fetch_input(2023, 2, false).unwrap();
+        let second = last_request(&stamp).unwrap();
+        // both times come from the same clock the wait is computed with
+        assert!(second - first >= Duration::from_millis(300));
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn submits_answer() {
+        let page = "<html><main><article><p>That's not the right answer; your answer is too high. \
+            Please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article></main></html>";
+        let (base_url, requests) = stub_server(vec![(200, page)]);
+        let cache = temp_dir("submit");
+        let client = Client::new(&config(&base_url, 0.0), &cache).unwrap();
+
+        assert_eq!(
+            client.submit_answer(2023, 5, 2, "1234"),
+            Ok(Outcome::TooHigh)
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.ends_with("level=2&answer=1234"));
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        let article = |text: &str| format!("<article><p>{text}</p></article>");
+        assert_eq!(
+            Outcome::parse(&article(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&article(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(&article(
+                "That's not the right answer. If you're stuck, ..."
+            )),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse(&article(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Outcome::Wait { seconds: 65 }
+        );
+        assert_eq!(
+            Outcome::parse(&article("You don't seem to be solving the right level.")),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            Outcome::parse("<html>teapot</html>"),
+            Outcome::Unknown("teapot".to_string())
+        );
+    }
+
+    #[test]
+    fn needs_session() {
+        let config = Config::parse("base_url = \"http://localhost\"").unwrap();
+        assert!(Client::new(&config, Path::new("unused")).is_err());
+        assert!(Config::parse("sesion = \"typo\"").is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// everything that can go wrong while solving a day
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AocError {
+    /// input could not be loaded
+    Input { day: u8, message: String },
+    /// input is malformed, line and column are 1-based
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+    /// input parsed fine but the puzzle has no solution for it (e.g. no reflection found)
+    Solve { day: u8, message: String },
+    /// solver panicked, which is a bug in the solver rather than a problem with the input
+    Panic { day: u8, message: String },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Input { day, message } => {
+                write!(f, "Day {day}: could not read input: {message}")
+            }
+            AocError::Parse {
+                day,
+                line,
+                column,
+                text,
+                message,
+            } => write!(
+                f,
+                "Day {day}: line {line}, column {column}: {message} (at {text:?})"
+            ),
+            AocError::Solve { day, message } => write!(f, "Day {day}: {message}"),
+            AocError::Panic { day, message } => write!(f, "Day {day} failed: {message}"),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+impl AocError {
+    pub fn solve(day: u8, message: impl Into<String>) -> Self {
+        AocError::Solve {
+            day,
+            message: message.into(),
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        match self {
+            AocError::Input { day, .. }
+            | AocError::Parse { day, .. }
+            | AocError::Solve { day, .. }
+            | AocError::Panic { day, .. } => *day,
+        }
+    }
+}
+
+/// helper for parsers that knows the day and the whole input, so errors can point at the offending text
+/// `text` arguments are expected to be slices of the input (as returned by `lines`, `split_once` etc.)
+/// for anything else the error is reported at line 0, column 0
+#[derive(Clone, Copy)]
+pub struct ParseContext<'a> {
+    day: u8,
+    input: &'a str,
+}
+
+impl<'a> ParseContext<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        ParseContext { day, input }
+    }
+
+    pub fn error(&self, text: &str, message: impl Into<String>) -> AocError {
+        let (line, column) = self.locate(text);
+        AocError::Parse {
+            day: self.day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// like `str::split_once`, but missing separators are an error
+    pub fn split_once<'b>(
+        &self,
+        text: &'b str,
+        separator: &str,
+    ) -> Result<(&'b str, &'b str), AocError> {
+        text.split_once(separator)
+            .ok_or_else(|| self.error(text, format!("expected {separator:?}")))
+    }
+
+    pub fn number<T: FromStr>(&self, text: &str) -> Result<T, AocError> {
+        text.trim()
+            .parse()
+            .map_err(|_| self.error(text, "expected a number"))
+    }
+
+    /// 1-based line and column of `text` within the input
+    fn locate(&self, text: &str) -> (usize, usize) {
+        let start = self.input.as_ptr() as usize;
+        let position = text.as_ptr() as usize;
+        if position < start || position > start + self.input.len() {
+            return (0, 0);
+        }
+
+        let before = &self.input.as_bytes()[..position - start];
+        let line = before.iter().filter(|&&byte| byte == b'\n').count() + 1;
+        let line_start = before
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |idx| idx + 1);
+        let column = String::from_utf8_lossy(&before[line_start..])
+            .chars()
+            .count()
+            + 1;
+
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14\nsoil: 5x\nsœur: café 12\n";
+
+    #[test]
+    fn locates_slices_of_the_input() {
+        let ctx = ParseContext::new(5, INPUT);
+        let lines: Vec<&str> = INPUT.lines().collect();
+        assert_eq!(ctx.locate(&lines[0][7..]), (1, 8));
+        assert_eq!(ctx.locate(&lines[1][6..]), (2, 7));
+        // columns count chars, "œ" and "é" take two bytes each
+        assert_eq!(ctx.locate(&lines[2][13..]), (3, 12));
+        assert_eq!(ctx.locate(&INPUT[INPUT.len()..]), (4, 1));
+    }
+
+    #[test]
+    fn other_strings_are_at_zero() {
+        let ctx = ParseContext::new(5, INPUT);
+        let copy = INPUT.to_string();
+        assert_eq!(ctx.locate(&copy[14..]), (0, 0));
+        assert_eq!(ctx.locate(&String::from("soil")), (0, 0));
+    }
+
+    #[test]
+    fn helpers_point_at_the_bad_text() {
+        let ctx = ParseContext::new(5, INPUT);
+        let line = INPUT.lines().nth(1).unwrap();
+        let (name, value) = ctx.split_once(line, ": ").unwrap();
+        assert_eq!((name, value), ("soil", "5x"));
+        assert_eq!(
+            ctx.number::<u32>(value),
+            Err(AocError::Parse {
+                day: 5,
+                line: 2,
+                column: 7,
+                text: "5x".to_string(),
+                message: "expected a number".to_string(),
+            })
+        );
+        assert!(matches!(
+            ctx.split_once(line, " -> "),
+            Err(AocError::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert_eq!(ctx.number::<u32>(" 12 "), Ok(12));
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+pub const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// random number generator for the input generators, the same seed always gives the same input
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// `len` random characters from `alphabet`
+pub fn word(rng: &mut StdRng, alphabet: &str, len: usize) -> String {
+    let chars: Vec<char> = alphabet.chars().collect();
+    (0..len)
+        .map(|_| chars[rng.gen_range(0..chars.len())])
+        .collect()
+}
+
+/// `count` different words of `len` characters from `alphabet`, none of them in `taken`
+/// there have to be enough possible words
+pub fn distinct_words(
+    rng: &mut StdRng,
+    alphabet: &str,
+    len: usize,
+    count: usize,
+    taken: &[&str],
+) -> Vec<String> {
+    let mut seen: HashSet<String> = taken.iter().map(|word| word.to_string()).collect();
+    let mut words = vec![];
+    while words.len() < count {
+        let word = word(rng, alphabet, len);
+        if seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        for (&(year, day), solver) in crate::registry().iter() {
+            for seed in 0..3 {
+                let Some(input) = solver.generate(&mut rng(seed), 8) else {
+                    continue;
+                };
+                if let Err(err) = crate::solve_day(day, solver.as_ref(), &input, &[1, 2]) {
+                    panic!("{year} day {day}, seed {seed}: {err}\n{input}");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "reference")]
+    #[test]
+    fn reference_solvers_agree() {
+        for (&(year, day), solver) in crate::registry().iter() {
+            for seed in 0..3 {
+                let Some(input) = solver.generate(&mut rng(seed), 3) else {
+                    continue;
+                };
+                if let Err(err) = crate::check_reference(day, solver.as_ref(), &input) {
+                    panic!("{year} day {day}, seed {seed}: {err}\n{input}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for solver in crate::registry().values() {
+            assert_eq!(
+                solver.generate(&mut rng(7), 5),
+                solver.generate(&mut rng(7), 5)
+            );
+        }
+    }
+}
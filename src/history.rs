@@ -0,0 +1,226 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Outcome;
+
+/// name of the history file, kept next to the cached inputs by default
+pub const HISTORY_FILE: &str = "submissions.jsonl";
+
+/// one submitted answer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// seconds since the unix epoch
+    pub time: u64,
+}
+
+/// every answer ever submitted, stored as one json record per line so that appending is cheap
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// a missing file is an empty history
+    pub fn load(path: &Path) -> Result<History, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("could not read {}: {err}", path.display())),
+        };
+
+        let attempts = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(nr, line)| {
+                serde_json::from_str(line)
+                    .map_err(|err| format!("{}:{}: {err}", path.display(), nr + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |attempt| {
+            attempt.year == year && attempt.day == day && attempt.part == part
+        })
+    }
+
+    /// reason not to submit `answer`, if the history already tells whether it is right or wrong
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        if let Some(correct) = self
+            .attempts(year, day, part)
+            .find(|attempt| attempt.outcome == Outcome::Correct)
+        {
+            return Err(if correct.answer == answer {
+                format!("{answer} was already accepted")
+            } else {
+                format!(
+                    "already solved with {}, {answer} must be wrong",
+                    correct.answer
+                )
+            });
+        }
+
+        if let Some(wrong) = self
+            .attempts(year, day, part)
+            .find(|attempt| attempt.answer == answer && attempt.outcome.is_wrong())
+        {
+            return Err(format!("{answer} was already rejected ({})", wrong.outcome));
+        }
+
+        // numeric answers can also be ruled out by the bounds from earlier too high/low attempts
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |outcome: Outcome| {
+            self.attempts(year, day, part)
+                .filter(move |attempt| attempt.outcome == outcome)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        if let Some(too_high) = bound(Outcome::TooHigh).min() {
+            if value >= too_high {
+                return Err(format!(
+                    "{answer} can't be right, {too_high} was already too high"
+                ));
+            }
+        }
+        if let Some(too_low) = bound(Outcome::TooLow).max() {
+            if value <= too_low {
+                return Err(format!(
+                    "{answer} can't be right, {too_low} was already too low"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// adds an attempt and appends it to the file
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        outcome: Outcome,
+    ) -> Result<(), String> {
+        let attempt = Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |time| time.as_secs()),
+        };
+
+        let line = serde_json::to_string(&attempt).unwrap() + "\n";
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|err| format!("could not write {}: {err}", self.path.display()))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(attempts: &[(&str, Outcome)]) -> History {
+        History {
+            path: PathBuf::new(),
+            attempts: attempts
+                .iter()
+                .map(|(answer, outcome)| Attempt {
+                    year: 2023,
+                    day: 5,
+                    part: 1,
+                    answer: answer.to_string(),
+                    outcome: outcome.clone(),
+                    time: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let history = history(&[
+            ("abc", Outcome::Wrong),
+            ("42", Outcome::Wait { seconds: 30 }),
+        ]);
+        assert!(history.check(2023, 5, 1, "abc").is_err());
+        assert!(history.check(2024, 5, 1, "abc").is_ok());
+        assert!(history.check(2023, 5, 2, "abc").is_ok());
+        assert!(history.check(2023, 6, 1, "abc").is_ok());
+        // a rate limited answer was never checked
+        assert!(history.check(2023, 5, 1, "42").is_ok());
+    }
+
+    #[test]
+    fn uses_bounds() {
+        let history = history(&[
+            ("100", Outcome::TooHigh),
+            ("80", Outcome::TooHigh),
+            ("10", Outcome::TooLow),
+        ]);
+        assert!(history.check(2023, 5, 1, "80").is_err());
+        assert!(history.check(2023, 5, 1, "90").is_err());
+        assert!(history.check(2023, 5, 1, "10").is_err());
+        assert!(history.check(2023, 5, 1, "-3").is_err());
+        assert!(history.check(2023, 5, 1, "11").is_ok());
+        assert!(history.check(2023, 5, 1, "79").is_ok());
+        assert!(history.check(2023, 5, 1, "text").is_ok());
+    }
+
+    #[test]
+    fn stops_after_correct_answer() {
+        let history = history(&[("7", Outcome::TooLow), ("12", Outcome::Correct)]);
+        assert!(history.check(2023, 5, 1, "12").is_err());
+        assert!(history.check(2023, 5, 1, "13").is_err());
+    }
+
+    #[test]
+    fn round_trips_through_file() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        history.record(2023, 3, 2, "123", Outcome::TooHigh).unwrap();
+        history
+            .record(2023, 3, 2, "99", Outcome::Wait { seconds: 45 })
+            .unwrap();
+
+        let history = History::load(&path).unwrap();
+        let outcomes: Vec<_> = history
+            .attempts(2023, 3, 2)
+            .map(|attempt| attempt.outcome.clone())
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![Outcome::TooHigh, Outcome::Wait { seconds: 45 }]
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+}
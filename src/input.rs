@@ -0,0 +1,85 @@
+use std::env;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use crate::LEGACY_YEAR;
+
+/// environment variable overriding the default input directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// where puzzle inputs are loaded from at runtime
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// directory containing either `YYYY/dayNN.txt` or `YYYY/dayNN/input.txt` per puzzle
+    /// (or the same without the year directory, for inputs of `LEGACY_YEAR`)
+    Directory(PathBuf),
+    /// one explicit file, used for whichever day is run
+    File(PathBuf),
+    /// read everything from stdin (only makes sense for a single day)
+    Stdin,
+}
+
+/// `$AOC_INPUT_DIR` if set, `inputs/` otherwise
+pub fn default_input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .unwrap_or_else(|| DEFAULT_INPUT_DIR.into())
+        .into()
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(default_input_dir())
+    }
+}
+
+impl InputSource {
+    /// "-" means stdin, anything else is a file path
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        }
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::Directory(dir) => {
+                let mut dirs = vec![dir.join(year.to_string())];
+                if year == LEGACY_YEAR {
+                    dirs.push(dir.clone());
+                }
+                let candidates: Vec<PathBuf> = dirs
+                    .iter()
+                    .flat_map(|dir| {
+                        [
+                            dir.join(format!("day{day:02}.txt")),
+                            dir.join(format!("day{day:02}")).join("input.txt"),
+                        ]
+                    })
+                    .collect();
+
+                for path in candidates.iter() {
+                    if path.is_file() {
+                        return read_to_string(path);
+                    }
+                }
+                let tried: Vec<String> = candidates
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+                Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no input for {year} day {day}, tried {}", tried.join(", ")),
+                ))
+            }
+            InputSource::File(path) => read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
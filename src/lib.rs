@@ -1,55 +1,53 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+pub mod artifacts;
+pub mod bench;
+pub mod client;
+mod error;
+pub mod generate;
+pub mod history;
+mod input;
+pub mod report;
+mod runner;
+pub mod scaffold;
+mod solver;
+pub mod stepper;
+pub mod util;
+mod verify;
+mod year2023;
+// new years are inserted above this line by `aoc new-day`
 
-pub fn get_days() -> Vec<fn()> {
-    vec![
-        day01::run_day,
-        day02::run_day,
-        day03::run_day,
-        day04::run_day,
-        day05::run_day,
-        day06::run_day,
-        day07::run_day,
-        day08::run_day,
-        day09::run_day,
-        day10::run_day,
-        day11::run_day,
-        day12::run_day,
-        day13::run_day,
-        day14::run_day,
-        day15::run_day,
-        day16::run_day,
-        day17::run_day,
-        day18::run_day,
-        day19::run_day,
-        day20::run_day,
-        day21::run_day,
-        day22::run_day,
-        day23::run_day,
-        day24::run_day,
-        day25::run_day,
-    ]
-}
\ No newline at end of file
+use std::collections::BTreeMap;
+
+pub use artifacts::Artifact;
+pub use error::{AocError, ParseContext};
+pub use input::{default_input_dir, InputSource, INPUT_DIR_VAR};
+pub use runner::{
+    check_reference, emit_day, parse_days, print_solution, run_days, solve_day, DaySolution,
+    PartSolution,
+};
+pub use solver::{check_parse, check_round_trip, Answer, DynSolver, Solver};
+pub use stepper::{Frame, Stepper};
+pub use verify::{ExpectedAnswers, Verdict, DEFAULT_ANSWERS_FILE};
+
+/// year of files written before puzzles were keyed by year
+/// (inputs directly in the input dir, `[dayNN]` sections in the answers file)
+pub const LEGACY_YEAR: u16 = 2023;
+
+/// all implemented puzzles, keyed by (year, day)
+pub type Registry = BTreeMap<(u16, u8), Box<dyn DynSolver>>;
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    year2023::register(&mut registry);
+    // new years are inserted above this line by `aoc new-day`
+    registry
+}
+
+/// what every fuzz target does with its input: `check_parse` for each of `days`, panicking so the fuzzer keeps the input
+pub fn fuzz_parsers(year: u16, days: &[u8], input: &str) {
+    let registry = registry();
+    for &day in days {
+        if let Err(err) = check_parse(registry[&(year, day)].as_ref(), input) {
+            panic!("day {day}: {err}");
+        }
+    }
+}
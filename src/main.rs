@@ -1,12 +1,912 @@
-use std::env;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let args = env::args().collect::<Vec<String>>();
-    if 2 == args.len() {
-        aoc_2023::get_days()[args[1].parse::<usize>().unwrap() - 1]();
+use aoc::artifacts::{Emitter, DEFAULT_OUTPUT_DIR};
+use aoc::client::{Client, Config, Fetched, Outcome};
+use aoc::history::{History, HISTORY_FILE};
+use aoc::util::image::ImageFormat;
+use aoc::{bench, report, scaffold};
+use aoc::{Answer, AocError, ExpectedAnswers, InputSource, PartSolution, Registry, Verdict};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// year of the puzzles (default: the latest implemented year)
+    #[arg(long, global = true)]
+    year: Option<u16>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// solve the selected days (all days if no subcommand is given)
+    Run(RunArgs),
+    /// compare the answers of the selected days to a file of known answers
+    Verify(VerifyArgs),
+    /// time parsing and both parts of the selected days
+    Bench(BenchArgs),
+    /// manage the local copies of puzzle inputs
+    #[command(subcommand)]
+    Inputs(InputsCommand),
+    /// solve one part and submit the answer, unless earlier submissions already show it is wrong
+    Submit(SubmitArgs),
+    /// create and register a module for a new day from the template
+    NewDay(NewDayArgs),
+    /// print a random input for a day
+    Generate(GenerateArgs),
+    /// solve random inputs for the selected days, to find crashes, slow cases and (with --reference) wrong answers
+    Stress(StressArgs),
+    /// step through the simulation behind a day in the terminal (days 14, 16, 20 and 22)
+    View(ViewArgs),
+}
+
+#[derive(Subcommand)]
+enum InputsCommand {
+    /// download the inputs of the selected days into the input dir, unless they're there already
+    Fetch(FetchArgs),
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// days to fetch, e.g. "7" or "1-5,7"
+    days: String,
+
+    /// download again even if the input is cached
+    #[arg(long)]
+    force: bool,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    day: u8,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// file recording every submitted answer, default: submissions.jsonl in the input dir
+    #[arg(long, value_name = "FILE")]
+    history: Option<PathBuf>,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    day: u8,
+
+    /// source directory of the crate
+    #[arg(long, value_name = "DIR", default_value = "src")]
+    src: PathBuf,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    day: u8,
+
+    #[command(flatten)]
+    generator: GeneratorArgs,
+}
+
+#[derive(Args)]
+struct StressArgs {
+    /// days to stress, e.g. "7" or "1-5,7" (default: all days)
+    days: Option<String>,
+
+    /// inputs to solve per day, round n uses seed + n
+    #[arg(long, default_value_t = 10)]
+    rounds: u64,
+
+    /// also compare the answers with the slow reference solvers, best with a small --size
+    #[arg(long)]
+    reference: bool,
+
+    #[command(flatten)]
+    generator: GeneratorArgs,
+}
+
+#[derive(Args)]
+struct ViewArgs {
+    day: u8,
+
+    /// input file ("-" for stdin), default: $AOC_INPUT_DIR or inputs/
+    #[arg(long, value_name = "FILE")]
+    input: Option<PathBuf>,
+
+    /// step to start at
+    #[arg(long, default_value_t = 0)]
+    step: usize,
+
+    /// print that step and exit instead of opening the viewer, e.g. when not running in a terminal
+    #[arg(long)]
+    print: bool,
+}
+
+#[derive(Args)]
+struct GeneratorArgs {
+    /// the same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// scales the input, what exactly grows depends on the day
+    #[arg(long, default_value_t = 10)]
+    size: usize,
+}
+
+/// overrides for the settings from aoc.toml and the environment
+#[derive(Args)]
+struct ClientArgs {
+    /// site to talk to, default: $AOC_BASE_URL, base_url in the config or https://adventofcode.com
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+
+    /// minimum number of seconds between two requests (default 5)
+    #[arg(long, value_name = "SECONDS")]
+    min_interval: Option<f64>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// days to run, e.g. "7" or "1-5,7"
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    days: Option<String>,
+
+    /// run every implemented day
+    #[arg(long)]
+    all: bool,
+
+    /// only solve one part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// input file ("-" for stdin), default: $AOC_INPUT_DIR or inputs/
+    #[arg(long, value_name = "FILE")]
+    input: Option<PathBuf>,
+
+    /// output format, json prints one array at the end, jsonl one record per line as each day is done,
+    /// both with a summary of the run as the last record
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// solve this many days at once (0: one per cpu core), output still comes in day order,
+    /// each day as soon as the days before it are done
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+
+    /// also write the maps, images and traces the days can produce to --output-dir
+    #[arg(long)]
+    emit: bool,
+
+    /// directory for --emit, its manifest.json lists what the last run wrote
+    #[arg(long, value_name = "DIR", default_value = DEFAULT_OUTPUT_DIR, requires = "emit")]
+    output_dir: PathBuf,
+
+    /// format of the images written by --emit
+    #[arg(long, value_enum, default_value_t = ImageFormat::Png, requires = "emit")]
+    image_format: ImageFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Jsonl,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// days to verify, e.g. "7" or "1-5,7" (default: all days)
+    days: Option<String>,
+
+    /// file with the expected answers
+    #[arg(long, value_name = "FILE", default_value = aoc::DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// days to benchmark, e.g. "7" or "1-5,7" (default: all days)
+    days: Option<String>,
+
+    /// untimed runs of each stage before measuring
+    #[arg(long, default_value_t = 1)]
+    warmup: usize,
+
+    /// timed runs of each stage
+    #[arg(long, default_value_t = 10)]
+    iterations: usize,
+
+    /// save the results as csv
+    #[arg(long, value_name = "FILE")]
+    save: Option<PathBuf>,
+
+    /// compare against results saved by an earlier run
+    #[arg(long, value_name = "FILE")]
+    baseline: Option<PathBuf>,
+
+    /// median slowdown (in percent) above which a stage counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let year = cli.year;
+
+    match cli.command {
+        Some(Command::Verify(args)) => verify(year, args),
+        Some(Command::Bench(args)) => bench(year, args),
+        Some(Command::Run(args)) => run(year, args),
+        Some(Command::Inputs(InputsCommand::Fetch(args))) => fetch(year, args),
+        Some(Command::Submit(args)) => submit(year, args),
+        Some(Command::NewDay(args)) => new_day(year, args),
+        Some(Command::Generate(args)) => generate(year, args),
+        Some(Command::Stress(args)) => stress(year, args),
+        Some(Command::View(args)) => view(year, args),
+        None => run(
+            year,
+            RunArgs {
+                days: None,
+                all: true,
+                part: None,
+                input: None,
+                format: Format::Text,
+                jobs: 1,
+                emit: false,
+                output_dir: PathBuf::from(DEFAULT_OUTPUT_DIR),
+                image_format: ImageFormat::Png,
+            },
+        ),
+    }
+}
+
+fn run(year: Option<u16>, args: RunArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, days) = match select_days(year, args.days.as_deref(), &registry) {
+        Ok(selection) => selection,
+        Err(code) => return code,
+    };
+
+    let source = match &args.input {
+        Some(path) => InputSource::from_arg(&path.to_string_lossy()),
+        None => InputSource::default(),
+    };
+    if args.input.is_some() && days.len() > 1 {
+        return usage_error("--input can only be used with a single day");
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut failures = 0;
+    let mut records = vec![];
+    let mut cpu_time = Duration::ZERO;
+    let mut emitter = args
+        .emit
+        .then(|| Emitter::new(&args.output_dir, args.image_format));
+    let start = Instant::now();
+
+    // the artifacts are made from the same input, stdin can't be read twice
+    let solve = |day| {
+        let input = source.load(year, day).map_err(|err| AocError::Input {
+            day,
+            message: err.to_string(),
+        })?;
+        let solver = registry[&(year, day)].as_ref();
+        let solution = aoc::solve_day(day, solver, &input, &parts)?;
+        Ok((
+            solution,
+            args.emit.then(|| aoc::emit_day(day, solver, &input)),
+        ))
+    };
+
+    let run_result = aoc::run_days(&days, args.jobs, solve, |day, result| {
+        let result = result.map(|(solution, artifacts)| {
+            let written = match (artifacts, &mut emitter) {
+                (Some(Ok(artifacts)), Some(emitter)) => emitter.write(year, day, &artifacts),
+                (Some(Err(err)), _) => Err(err.to_string()),
+                _ => Ok(()),
+            };
+            if let Err(err) = written {
+                eprintln!("{err}");
+                failures += 1;
+            }
+            solution
+        });
+
+        match (&result, args.format) {
+            (Ok(solution), Format::Text) => {
+                aoc::print_solution(registry[&(year, day)].as_ref(), solution);
+                println!();
+            }
+            (_, Format::Json) => records.extend(
+                report::records(year, day, &parts, &result)
+                    .into_iter()
+                    .map(report::Line::Part),
+            ),
+            (_, Format::Jsonl) => {
+                for record in report::records(year, day, &parts, &result) {
+                    println!("{}", serde_json::to_string(&record).unwrap());
+                }
+            }
+            (Err(_), Format::Text) => {}
+        }
+
+        match result {
+            Ok(solution) => cpu_time += solution.cpu_time,
+            Err(err) => {
+                eprintln!("{err}");
+                failures += 1;
+            }
+        }
+    });
+    if let Err(err) = run_result {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    let wall_clock = start.elapsed();
+    let summary = report::Line::Summary {
+        summary: report::Summary {
+            year,
+            days: days.len(),
+            failed: failures,
+            wall_clock_ms: wall_clock.as_secs_f64() * 1000.0,
+            cpu_time_ms: cpu_time.as_secs_f64() * 1000.0,
+        },
+    };
+    match args.format {
+        // with several jobs the wall clock time is less than the time spent solving
+        Format::Text => {
+            println!("Total: {wall_clock:.2?} wall clock, {cpu_time:.2?} cpu time summed over days")
+        }
+        Format::Json => {
+            records.push(summary);
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        }
+        Format::Jsonl => println!("{}", serde_json::to_string(&summary).unwrap()),
+    }
+
+    if let Some(emitter) = emitter {
+        match emitter.finish() {
+            // stdout is only for the json with the other formats
+            Ok((manifest, entries)) => eprintln!(
+                "Wrote {} artifact(s), listed in {}",
+                entries.len(),
+                manifest.display()
+            ),
+            Err(err) => {
+                eprintln!("{err}");
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("{failures} day(s) failed");
+        ExitCode::FAILURE
     } else {
-        for call in aoc_2023::get_days() {
-            call();
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(year: Option<u16>, args: VerifyArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, days) = match select_days(year, args.days.as_deref(), &registry) {
+        Ok(selection) => selection,
+        Err(code) => return code,
+    };
+    let expected = match ExpectedAnswers::load(&args.answers) {
+        Ok(expected) => expected,
+        Err(err) => return usage_error(&err),
+    };
+
+    let source = InputSource::default();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days {
+        let result = source
+            .load(year, day)
+            .map_err(|err| AocError::Input {
+                day,
+                message: err.to_string(),
+            })
+            .and_then(|input| {
+                aoc::solve_day(day, registry[&(year, day)].as_ref(), &input, &[1, 2])
+            });
+
+        let solution = match result {
+            Ok(solution) => solution,
+            Err(err) => {
+                eprintln!("{err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        for PartSolution { part, answer, .. } in solution.parts.iter() {
+            if *answer == Answer::None {
+                continue;
+            }
+            match expected.check(year, day, *part, answer) {
+                Verdict::Pass => {
+                    println!("Day {day} Part {part}: pass");
+                    passed += 1;
+                }
+                Verdict::Fail { expected } => {
+                    println!("Day {day} Part {part}: FAIL");
+                    println!("    - {expected}");
+                    println!("    + {answer}");
+                    failed += 1;
+                }
+                Verdict::Missing => {
+                    println!("Day {day} Part {part}: missing (computed {answer})");
+                    missing += 1;
+                }
+            }
         }
     }
-}
\ No newline at end of file
+
+    println!();
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(year: Option<u16>, args: BenchArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, days) = match select_days(year, args.days.as_deref(), &registry) {
+        Ok(selection) => selection,
+        Err(code) => return code,
+    };
+    let baseline = match &args.baseline {
+        Some(path) => match bench::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(err) => return usage_error(&err),
+        },
+        None => None,
+    };
+
+    let source = InputSource::default();
+    let mut results = BTreeMap::new();
+    let mut failures = 0;
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+        "day", "stage", "min", "median", "p95"
+    );
+
+    for day in days {
+        let result = source
+            .load(year, day)
+            .map_err(|err| AocError::Input {
+                day,
+                message: err.to_string(),
+            })
+            .and_then(|input| {
+                bench::bench_day(
+                    day,
+                    registry[&(year, day)].as_ref(),
+                    &input,
+                    args.warmup,
+                    args.iterations,
+                )
+            });
+
+        let timings = match result {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("{err}");
+                failures += 1;
+                continue;
+            }
+        };
+
+        for (stage, stats) in timings.iter() {
+            let mut line = format!(
+                "{day:>3}  {stage:<5}  {:>10.2?}  {:>10.2?}  {:>10.2?}",
+                stats.min, stats.median, stats.p95
+            );
+            let old_stats = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(&(year, day)))
+                .and_then(|timings| timings.get(stage));
+            if let Some(old_stats) = old_stats {
+                let change = bench::median_change(old_stats, stats);
+                line += &format!("  {change:>+7.1}%");
+                if bench::is_regression(old_stats, stats, args.threshold) {
+                    line += "  REGRESSION";
+                    regressions += 1;
+                }
+            }
+            println!("{line}");
+        }
+
+        results.insert((year, day), timings);
+    }
+
+    let total_min: Duration = results
+        .values()
+        .flat_map(|timings| timings.values())
+        .map(|stats| stats.min)
+        .sum();
+    let total_median: Duration = results
+        .values()
+        .flat_map(|timings| timings.values())
+        .map(|stats| stats.median)
+        .sum();
+    println!();
+    println!(
+        "Total over {} days: {total_min:.2?} (sum of minimums), {total_median:.2?} (sum of medians)",
+        results.len()
+    );
+
+    if let Some(path) = &args.save {
+        if let Err(err) = bench::save(&results, path) {
+            eprintln!("{err}");
+            failures += 1;
+        }
+    }
+
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} stage(s) got more than {}% slower",
+            args.threshold
+        );
+    }
+    if failures > 0 || regressions > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(year: Option<u16>, args: FetchArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, days) = match select_days(year, Some(&args.days), &registry) {
+        Ok(selection) => selection,
+        Err(code) => return code,
+    };
+
+    let client = match client(args.client) {
+        Ok(client) => client,
+        Err(code) => return code,
+    };
+
+    let mut failures = 0;
+    for day in days {
+        match client.fetch_input(year, day, args.force) {
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {day}: downloaded to {}", path.display())
+            }
+            Ok(Fetched::Cached(path)) => println!("Day {day}: already in {}", path.display()),
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn submit(year: Option<u16>, args: SubmitArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, day) = match select_days(year, Some(&args.day.to_string()), &registry) {
+        Ok((year, days)) => (year, days[0]),
+        Err(code) => return code,
+    };
+    let history_path = args
+        .history
+        .unwrap_or_else(|| aoc::default_input_dir().join(HISTORY_FILE));
+    let mut history = match History::load(&history_path) {
+        Ok(history) => history,
+        Err(err) => return usage_error(&err),
+    };
+
+    let result = InputSource::default()
+        .load(year, day)
+        .map_err(|err| AocError::Input {
+            day,
+            message: err.to_string(),
+        })
+        .and_then(|input| {
+            aoc::solve_day(day, registry[&(year, day)].as_ref(), &input, &[args.part])
+        });
+    let answer = match result {
+        Ok(solution) => solution.parts[0].answer.clone(),
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if answer == Answer::None {
+        return usage_error(&format!(
+            "day {day} part {} has nothing to submit",
+            args.part
+        ));
+    }
+    let answer = answer.to_string();
+
+    if let Err(reason) = history.check(year, day, args.part, &answer) {
+        eprintln!("Day {day} Part {}: not submitting, {reason}", args.part);
+        return ExitCode::FAILURE;
+    }
+
+    let client = match client(args.client) {
+        Ok(client) => client,
+        Err(code) => return code,
+    };
+    let outcome = match client.submit_answer(year, day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Day {day} Part {}: {answer} is {outcome}", args.part);
+
+    if let Err(err) = history.record(year, day, args.part, &answer, outcome.clone()) {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+    if outcome == Outcome::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new_day(year: Option<u16>, args: NewDayArgs) -> ExitCode {
+    // unlike the other commands, this one may start a new year
+    let year = match year.or_else(|| aoc::registry().keys().last().map(|&(year, _)| year)) {
+        Some(year) => year,
+        None => return usage_error("no puzzles implemented yet, pass --year"),
+    };
+
+    match scaffold::new_day(&args.src, year, args.day) {
+        Ok(files) => {
+            for file in files {
+                println!("wrote {}", file.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => usage_error(&err),
+    }
+}
+
+fn generate(year: Option<u16>, args: GenerateArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, day) = match select_days(year, Some(&args.day.to_string()), &registry) {
+        Ok((year, days)) => (year, days[0]),
+        Err(code) => return code,
+    };
+    if args.generator.size == 0 {
+        return usage_error("--size must be at least 1");
+    }
+
+    let mut rng = aoc::generate::rng(args.generator.seed);
+    match registry[&(year, day)].generate(&mut rng, args.generator.size) {
+        Some(input) => {
+            print!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => usage_error(&format!("day {day} has no input generator")),
+    }
+}
+
+fn stress(year: Option<u16>, args: StressArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, days) = match select_days(year, args.days.as_deref(), &registry) {
+        Ok(selection) => selection,
+        Err(code) => return code,
+    };
+    let GeneratorArgs { seed, size } = args.generator;
+    if size == 0 {
+        return usage_error("--size must be at least 1");
+    }
+    if args.reference && !cfg!(feature = "reference") {
+        return usage_error(
+            "--reference needs the reference solvers, build with --features reference",
+        );
+    }
+
+    let mut failures = 0;
+    for day in days {
+        let solver = registry[&(year, day)].as_ref();
+        // seed of the slowest round, and how long it took
+        let mut slowest: Option<(u64, Duration)> = None;
+        let mut day_failures = 0;
+        let mut compared_parts = vec![];
+
+        for round_seed in (0..args.rounds).map(|round| seed.wrapping_add(round)) {
+            let Some(input) = solver.generate(&mut aoc::generate::rng(round_seed), size) else {
+                break;
+            };
+            match aoc::solve_day(day, solver, &input, &[1, 2]) {
+                Ok(solution) => {
+                    let time = solution.total_time();
+                    if slowest.is_none_or(|(_, slowest_time)| time > slowest_time) {
+                        slowest = Some((round_seed, time));
+                    }
+                }
+                Err(err) => {
+                    eprintln!("Day {day} seed {round_seed}: {err}");
+                    eprintln!(
+                        "    reproduce with: aoc --year {year} generate {day} --seed {round_seed} --size {size} | aoc --year {year} run {day} --input -"
+                    );
+                    day_failures += 1;
+                    continue;
+                }
+            }
+
+            if args.reference {
+                match aoc::check_reference(day, solver, &input) {
+                    Ok(parts) => compared_parts = parts,
+                    Err(err) => {
+                        eprintln!("Day {day} seed {round_seed}: {err}");
+                        eprintln!(
+                            "    reproduce with: aoc --year {year} stress {day} --reference --rounds 1 --seed {round_seed} --size {size}"
+                        );
+                        day_failures += 1;
+                    }
+                }
+            }
+        }
+
+        if day_failures > 0 {
+            println!("Day {day}: {day_failures} of {} rounds failed", args.rounds);
+        } else if let Some((slowest_seed, time)) = slowest {
+            let reference = match compared_parts[..] {
+                _ if !args.reference => String::new(),
+                [] => ", no reference solver".to_string(),
+                [part] => format!(", part {part} matches the reference"),
+                _ => ", both parts match the reference".to_string(),
+            };
+            println!("Day {day}: ok, slowest {time:.2?} with seed {slowest_seed}{reference}");
+        } else {
+            println!("Day {day}: no input generator, skipped");
+        }
+        failures += day_failures;
+    }
+
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn view(year: Option<u16>, args: ViewArgs) -> ExitCode {
+    let registry = aoc::registry();
+    let (year, day) = match select_days(year, Some(&args.day.to_string()), &registry) {
+        Ok((year, days)) => (year, days[0]),
+        Err(code) => return code,
+    };
+    let solver = registry[&(year, day)].as_ref();
+    let source = match &args.input {
+        Some(path) => InputSource::from_arg(&path.to_string_lossy()),
+        None => InputSource::default(),
+    };
+
+    let stepper = source
+        .load(year, day)
+        .map_err(|err| AocError::Input {
+            day,
+            message: err.to_string(),
+        })
+        .and_then(|input| solver.parse(&input))
+        .map(|input| solver.stepper(input.as_ref()));
+    let mut stepper = match stepper {
+        Ok(Some(stepper)) => stepper,
+        Ok(None) => return usage_error(&format!("day {day} has no simulation to step through")),
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.print {
+        let step = match stepper.last_step() {
+            Some(last_step) => args.step.min(last_step),
+            None => args.step,
+        };
+        let frame = stepper.frame(step);
+        println!("step {step}: {}", frame.counter_line());
+        print!("{}", frame.view);
+        return ExitCode::SUCCESS;
+    }
+    match aoc::stepper::view(&format!("Day {day} of {year}"), stepper.as_mut(), args.step) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// client configured from aoc.toml, the environment and the command line, in increasing priority
+fn client(args: ClientArgs) -> Result<Client, ExitCode> {
+    let mut config = Config::load().map_err(|err| usage_error(&err))?;
+    if args.base_url.is_some() {
+        config.base_url = args.base_url;
+    }
+    if args.min_interval.is_some() {
+        config.min_interval = args.min_interval;
+    }
+    Client::new(&config, &aoc::default_input_dir()).map_err(|err| usage_error(&err))
+}
+
+/// the year (latest implemented one for `None`) and its selected days, all days for `None`
+/// the selection must only contain implemented days
+fn select_days(
+    year: Option<u16>,
+    spec: Option<&str>,
+    registry: &Registry,
+) -> Result<(u16, Vec<u8>), ExitCode> {
+    let years: BTreeSet<u16> = registry.keys().map(|&(year, _)| year).collect();
+    let year = match year {
+        Some(year) if years.contains(&year) => year,
+        Some(year) => {
+            let available = years
+                .iter()
+                .map(|year| year.to_string())
+                .collect::<Vec<_>>();
+            return Err(usage_error(&format!(
+                "year {year} is not implemented (available: {})",
+                available.join(", ")
+            )));
+        }
+        None => *years
+            .last()
+            .ok_or_else(|| usage_error("no puzzles implemented"))?,
+    };
+    let implemented: Vec<u8> = registry
+        .keys()
+        .filter(|&&(puzzle_year, _)| puzzle_year == year)
+        .map(|&(_, day)| day)
+        .collect();
+
+    let days = match spec {
+        Some(spec) => aoc::parse_days(spec).map_err(|err| usage_error(&err))?,
+        None => implemented.clone(),
+    };
+
+    if let Some(day) = days.iter().find(|day| !implemented.contains(day)) {
+        let available = implemented
+            .iter()
+            .map(|day| day.to_string())
+            .collect::<Vec<_>>();
+        return Err(usage_error(&format!(
+            "day {day} of {year} is not implemented (available: {})",
+            available.join(", ")
+        )));
+    }
+
+    Ok((year, days))
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("error: {message}");
+    ExitCode::from(2)
+}
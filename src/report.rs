@@ -0,0 +1,174 @@
+use serde::Serialize;
+
+use crate::{Answer, AocError, DaySolution};
+
+/// one machine readable result line, one per day and part
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer_type: Option<&'static str>,
+    /// time spent on this part only, parsing is reported separately
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_duration_ms: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// totals of a whole run, what the last line of the text output shows
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Summary {
+    pub year: u16,
+    pub days: usize,
+    /// failed days, and days whose artifacts couldn't be written
+    pub failed: usize,
+    pub wall_clock_ms: f64,
+    /// cpu time of all days added up, more than the wall clock time when solving on several jobs
+    pub cpu_time_ms: f64,
+}
+
+/// one element of the json output, or line of the jsonl output
+/// the summary comes last, as `{"summary": {...}}` so it can't be taken for the result of a part
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum Line {
+    Part(Record),
+    Summary { summary: Summary },
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+}
+
+/// turns the result of running a day into records for the requested parts
+/// a failed day yields an error record for every requested part
+pub fn records(
+    year: u16,
+    day: u8,
+    parts: &[u8],
+    result: &Result<DaySolution, AocError>,
+) -> Vec<Record> {
+    match result {
+        Ok(solution) => solution
+            .parts
+            .iter()
+            .filter(|part| part.answer != Answer::None)
+            .map(|part| Record {
+                year,
+                day,
+                part: part.part,
+                status: Status::Ok,
+                answer: Some(part.answer.to_string()),
+                answer_type: Some(part.answer.type_name()),
+                duration_ms: Some(part.time.as_secs_f64() * 1000.0),
+                parse_duration_ms: Some(solution.parse_time.as_secs_f64() * 1000.0),
+                error: None,
+            })
+            .collect(),
+        Err(err) => parts
+            .iter()
+            .map(|&part| Record {
+                year,
+                day,
+                part,
+                status: Status::Error,
+                answer: None,
+                answer_type: None,
+                duration_ms: None,
+                parse_duration_ms: None,
+                error: Some(err.to_string()),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::PartSolution;
+
+    #[test]
+    fn records_of_solved_and_failed_days() {
+        let solution = DaySolution {
+            day: 25,
+            parse_time: Duration::from_millis(2),
+            parts: vec![
+                PartSolution {
+                    part: 1,
+                    answer: Answer::Unsigned(54),
+                    time: Duration::from_millis(5),
+                },
+                PartSolution {
+                    part: 2,
+                    answer: Answer::None,
+                    time: Duration::ZERO,
+                },
+            ],
+            cpu_time: Duration::from_millis(7),
+        };
+        // day 25 has no part 2, it gets no record
+        let solved = records(2023, 25, &[1, 2], &Ok(solution));
+        assert_eq!(
+            serde_json::to_value(&solved).unwrap(),
+            json!([{
+                "year": 2023,
+                "day": 25,
+                "part": 1,
+                "status": "ok",
+                "answer": "54",
+                "answer_type": "unsigned",
+                "duration_ms": 5.0,
+                "parse_duration_ms": 2.0,
+            }])
+        );
+
+        let failed = records(2023, 7, &[2], &Err(AocError::solve(7, "no answer")));
+        assert_eq!(
+            serde_json::to_value(&failed).unwrap(),
+            json!([{
+                "year": 2023,
+                "day": 7,
+                "part": 2,
+                "status": "error",
+                "error": AocError::solve(7, "no answer").to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn summary_line_is_wrapped() {
+        let summary = Line::Summary {
+            summary: Summary {
+                year: 2023,
+                days: 25,
+                failed: 1,
+                wall_clock_ms: 1.5,
+                cpu_time_ms: 3.0,
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(&summary).unwrap(),
+            json!({"summary": {
+                "year": 2023,
+                "days": 25,
+                "failed": 1,
+                "wall_clock_ms": 1.5,
+                "cpu_time_ms": 3.0,
+            }})
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use cpu_time::ThreadTime;
+use rayon::ThreadPoolBuilder;
+
+use crate::{Answer, AocError, Artifact, DynSolver};
+
+/// parses a day selection like "1-5,7" into a sorted list of days
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = BTreeSet::new();
+
+    for item in spec.split(',').map(|item| item.trim()) {
+        let (from, to) = match item.split_once('-') {
+            Some((from, to)) => (parse_day(from)?, parse_day(to)?),
+            None => (parse_day(item)?, parse_day(item)?),
+        };
+
+        if from > to {
+            return Err(format!("day range {item} is backwards"));
+        }
+        days.extend(from..=to);
+    }
+
+    Ok(days.into_iter().collect())
+}
+
+fn parse_day(day_str: &str) -> Result<u8, String> {
+    let day = day_str
+        .trim()
+        .parse()
+        .map_err(|_| format!("{day_str:?} is not a day number"))?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, not {day}"));
+    }
+    Ok(day)
+}
+
+/// answer of one part along with how long solving it took
+#[derive(Clone, Debug)]
+pub struct PartSolution {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// everything computed for one day
+#[derive(Clone, Debug)]
+pub struct DaySolution {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartSolution>,
+    /// cpu time of the thread that solved the day, less than the total time if it had to wait for a core
+    pub cpu_time: Duration,
+}
+
+impl DaySolution {
+    /// parse time plus the time spent on each part
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+}
+
+/// solves the given parts (1 and/or 2) for the input without printing anything
+/// a panicking solver is reported as an error instead of taking down the whole run
+pub fn solve_day(
+    day: u8,
+    solver: &dyn DynSolver,
+    input_str: &str,
+    parts: &[u8],
+) -> Result<DaySolution, AocError> {
+    catch_panic(day, || {
+        let cpu_start = ThreadTime::now();
+        let start = Instant::now();
+        let input = solver.parse(input_str)?;
+        let parse_time = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => solver.part_one(input.as_ref())?,
+                    _ => solver.part_two(input.as_ref())?,
+                };
+                Ok(PartSolution {
+                    part,
+                    answer,
+                    time: start.elapsed(),
+                })
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        Ok(DaySolution {
+            day,
+            parse_time,
+            parts,
+            cpu_time: cpu_start.elapsed(),
+        })
+    })
+}
+
+/// solves `input_str` with both the real and the reference solver of a day, a mismatch is an error
+/// returns the parts that were compared, the ones without a reference solver are left out
+pub fn check_reference(
+    day: u8,
+    solver: &dyn DynSolver,
+    input_str: &str,
+) -> Result<Vec<u8>, AocError> {
+    catch_panic(day, || {
+        let input = solver.parse(input_str)?;
+        let mut compared = vec![];
+
+        for part in [1, 2] {
+            let Some(expected) = solver.reference(input.as_ref(), part) else {
+                continue;
+            };
+            let expected = expected?;
+            let answer = match part {
+                1 => solver.part_one(input.as_ref())?,
+                _ => solver.part_two(input.as_ref())?,
+            };
+            // the reference may count in another integer type, only the number matters
+            if answer.to_string() != expected.to_string() {
+                return Err(AocError::solve(
+                    day,
+                    format!(
+                        "part {part} gives {answer}, but the reference solver gives {expected}"
+                    ),
+                ));
+            }
+            compared.push(part);
+        }
+
+        Ok(compared)
+    })
+}
+
+/// the artifacts of a day for `input_str`, computed apart from the answers so plain runs don't pay for them
+pub fn emit_day(
+    day: u8,
+    solver: &dyn DynSolver,
+    input_str: &str,
+) -> Result<Vec<Artifact>, AocError> {
+    catch_panic(day, || solver.emit(solver.parse(input_str)?.as_ref()))
+}
+
+/// solves every day with `solve`, on `jobs` threads (0 means one per cpu core)
+/// `on_done` is called in day order, as soon as a day and all days before it are done
+pub fn run_days<T: Send>(
+    days: &[u8],
+    jobs: usize,
+    solve: impl Fn(u8) -> Result<T, AocError> + Sync,
+    mut on_done: impl FnMut(u8, Result<T, AocError>),
+) -> Result<(), String> {
+    if jobs == 1 {
+        for &day in days {
+            on_done(day, solve(day));
+        }
+        return Ok(());
+    }
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|err| format!("could not start thread pool: {err}"))?;
+    let (sender, receiver) = mpsc::channel();
+    pool.in_place_scope(|scope| {
+        for (idx, &day) in days.iter().enumerate() {
+            let (sender, solve) = (sender.clone(), &solve);
+            scope.spawn(move |_| {
+                // the receiver only goes away if on_done panicked
+                let _ = sender.send((idx, solve(day)));
+            });
+        }
+        drop(sender);
+
+        // days that finish before an earlier one wait here for their turn
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+        for (idx, result) in receiver {
+            finished.insert(idx, result);
+            while let Some(result) = finished.remove(&next) {
+                on_done(days[next], result);
+                next += 1;
+            }
+        }
+    });
+    Ok(())
+}
+
+/// runs `solve`, turning a panic into `AocError::Panic`
+pub fn catch_panic<T>(day: u8, solve: impl FnOnce() -> Result<T, AocError>) -> Result<T, AocError> {
+    panic::catch_unwind(AssertUnwindSafe(solve)).unwrap_or_else(|payload| {
+        let message = match (
+            payload.downcast_ref::<&str>(),
+            payload.downcast_ref::<String>(),
+        ) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => "unknown panic".to_string(),
+        };
+        Err(AocError::Panic { day, message })
+    })
+}
+
+/// prints the answers of a solved day, one "Day N Part M: ..." line per part
+pub fn print_solution(solver: &dyn DynSolver, solution: &DaySolution) {
+    for part in solution.parts.iter() {
+        if part.answer != Answer::None {
+            println!(
+                "Day {} Part {}: {}",
+                solution.day,
+                part.part,
+                solver.describe(part.part, &part.answer)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn parses_day_ranges() {
+        assert_eq!(parse_days("1-5,7"), Ok(vec![1, 2, 3, 4, 5, 7]));
+        assert_eq!(parse_days(" 7, 3 - 4,4"), Ok(vec![3, 4, 7]));
+        assert_eq!(parse_days("25"), Ok(vec![25]));
+
+        assert_eq!(
+            parse_days("5-1"),
+            Err("day range 5-1 is backwards".to_string())
+        );
+        for spec in ["", " ", "1,,2", "3-", "x", "1-5-7"] {
+            assert!(parse_days(spec).is_err(), "{spec:?}");
+        }
+        for spec in ["0", "26", "20-26", "300"] {
+            assert!(parse_days(spec).is_err(), "{spec:?}");
+        }
+    }
+
+    #[test]
+    fn hands_out_days_in_order_on_several_threads() {
+        let days: Vec<u8> = (1..=8).collect();
+        let mut done = vec![];
+        run_days(
+            &days,
+            4,
+            |day| {
+                // the later days finish first
+                std::thread::sleep(Duration::from_millis(5 * (9 - day as u64)));
+                match day {
+                    3 => Err(AocError::solve(day, "no answer")),
+                    _ => Ok(day as u32 * 10),
+                }
+            },
+            |day, result| done.push((day, result.ok())),
+        )
+        .unwrap();
+
+        let expected: Vec<(u8, Option<u32>)> = days
+            .iter()
+            .map(|&day| (day, (day != 3).then_some(day as u32 * 10)))
+            .collect();
+        assert_eq!(done, expected);
+    }
+
+    #[test]
+    fn hands_out_days_before_slower_later_ones_are_done() {
+        let slow_day_done = AtomicBool::new(false);
+        let mut done = vec![];
+        run_days(
+            &[1, 2],
+            2,
+            |day| {
+                if day == 2 {
+                    std::thread::sleep(Duration::from_millis(300));
+                    slow_day_done.store(true, Ordering::SeqCst);
+                }
+                Ok(())
+            },
+            |day, _| done.push((day, slow_day_done.load(Ordering::SeqCst))),
+        )
+        .unwrap();
+        assert_eq!(done, vec![(1, false), (2, true)]);
+    }
+}
@@ -0,0 +1,267 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// marks the end of the `mod dayNN;` lines and of the registrations in a year module
+const DAY_MARKER: &str = "// new days are inserted above this line by `aoc new-day`";
+/// marks the end of the `mod yearNNNN;` lines and of the registrations in lib.rs
+const YEAR_MARKER: &str = "// new years are inserted above this line by `aoc new-day`";
+
+const DAY_TEMPLATE: &str = r##"use crate::{Answer, AocError, ParseContext, Solver};
+
+pub struct Day{{day:02}};
+
+impl Solver for Day{{day:02}} {
+    type Input = Vec<String>;
+
+    const PART_ONE: &'static str = "The answer to part one is {}";
+    const PART_TWO: &'static str = "The answer to part two is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        part_two(input)
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<String>, AocError> {
+    let ctx = ParseContext::new({{day}}, input_str);
+
+    input_str
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                return Err(ctx.error(line, "expected a non-empty line"));
+            }
+            Ok(line.to_string())
+        })
+        .collect()
+}
+
+fn part_one(_input: &[String]) -> Result<Answer, AocError> {
+    Ok(Answer::None)
+}
+
+fn part_two(_input: &[String]) -> Result<Answer, AocError> {
+    Ok(Answer::None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    // fill in example_input.txt and the expected answers from the puzzle description,
+    // then drop the #[ignore]; until then these fail if run with --ignored
+    #[test]
+    #[ignore = "the example and its answer are not filled in yet"]
+    fn part_one_example() {
+        let input = Day{{day:02}}.parse(EXAMPLE).unwrap();
+        assert_eq!(Day{{day:02}}.part_one(&input).unwrap(), Answer::Unsigned(0));
+    }
+
+    #[test]
+    #[ignore = "the example and its answer are not filled in yet"]
+    fn part_two_example() {
+        let input = Day{{day:02}}.parse(EXAMPLE).unwrap();
+        assert_eq!(Day{{day:02}}.part_two(&input).unwrap(), Answer::Unsigned(0));
+    }
+}
+"##;
+
+const YEAR_TEMPLATE: &str = r##"// new days are inserted above this line by `aoc new-day`
+
+use crate::Registry;
+
+pub const YEAR: u16 = {{year}};
+
+/// adds all implemented days of {{year}} to the registry
+pub fn register(registry: &mut Registry) {
+    // new days are inserted above this line by `aoc new-day`
+}
+"##;
+
+/// creates `yearYYYY/dayNN/` below `src_dir` from the template, with an empty example input,
+/// and registers it (along with the year, if it is new)
+/// returns the created and modified files
+pub fn new_day(src_dir: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be between 1 and 25, not {day}"));
+    }
+    let lib_path = src_dir.join("lib.rs");
+    if !lib_path.is_file() {
+        return Err(format!(
+            "{} not found, run this from the root of the crate",
+            lib_path.display()
+        ));
+    }
+
+    let year_dir = src_dir.join(format!("year{year}"));
+    let year_path = year_dir.join("mod.rs");
+    let day_dir = year_dir.join(format!("day{day:02}"));
+    if day_dir.exists() {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let mut touched = vec![];
+
+    // work out all changes first, so nothing is written if one of them fails
+    let lib = read(&lib_path)?;
+    let new_year = !year_path.is_file();
+    let new_lib = if new_year {
+        let lib = insert_line(&lib, YEAR_MARKER, &format!("mod year{year};"))?;
+        let lib = insert_line(
+            &lib,
+            YEAR_MARKER,
+            &format!("    year{year}::register(&mut registry);"),
+        )?;
+        Some(lib)
+    } else {
+        None
+    };
+    let year_mod = if new_year {
+        fill(YEAR_TEMPLATE, year, day)
+    } else {
+        read(&year_path)?
+    };
+    let year_mod = insert_line(&year_mod, DAY_MARKER, &format!("mod day{day:02};"))?;
+    let year_mod = insert_line(
+        &year_mod,
+        DAY_MARKER,
+        &format!("    registry.insert((YEAR, {day}), Box::new(day{day:02}::Day{day:02}));"),
+    )?;
+
+    fs::create_dir_all(&day_dir)
+        .map_err(|err| format!("could not create {}: {err}", day_dir.display()))?;
+    write(
+        &day_dir.join("mod.rs"),
+        &fill(DAY_TEMPLATE, year, day),
+        &mut touched,
+    )?;
+    write(&day_dir.join("example_input.txt"), "", &mut touched)?;
+    write(&year_path, &year_mod, &mut touched)?;
+    if let Some(lib) = new_lib {
+        write(&lib_path, &lib, &mut touched)?;
+    }
+
+    Ok(touched)
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day:02}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// inserts `new_line` into the block of similar lines (same text up to the first digit) right above `marker`,
+/// keeping the block sorted by the first number in each line
+/// the marker has to be indented like `new_line`, which tells apart the markers for declarations and registrations
+fn insert_line(content: &str, marker: &str, new_line: &str) -> Result<String, String> {
+    let number = |line: &str| -> Option<u32> {
+        let start = line.find(|c: char| c.is_ascii_digit())?;
+        let digits = &line[start..];
+        let end = digits
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(digits.len());
+        digits[..end].parse().ok()
+    };
+    let prefix_len = new_line
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(new_line.len());
+    let prefix = &new_line[..prefix_len];
+    let key = number(new_line);
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    let indent = &new_line[..new_line.len() - new_line.trim_start().len()];
+    let marker_idx = lines
+        .iter()
+        .position(|line| line.trim() == marker && line.strip_prefix(indent) == Some(line.trim()))
+        .ok_or(format!("could not find the line {marker:?}"))?;
+
+    let mut insert_at = marker_idx;
+    for idx in (0..marker_idx).rev() {
+        if !lines[idx].starts_with(prefix) {
+            break;
+        }
+        if number(lines[idx]) == key {
+            return Err(format!("{:?} is already registered", lines[idx].trim()));
+        }
+        if number(lines[idx]) > key {
+            insert_at = idx;
+        }
+    }
+
+    lines.insert(insert_at, new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+fn write(path: &Path, content: &str, touched: &mut Vec<PathBuf>) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    touched.push(path.to_path_buf());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_sorted() {
+        let content = "mod day02;\nmod day10;\n// marker\n\nfn main() {}\n";
+        assert_eq!(
+            insert_line(content, "// marker", "mod day05;").unwrap(),
+            "mod day02;\nmod day05;\nmod day10;\n// marker\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            insert_line(content, "// marker", "mod day11;").unwrap(),
+            "mod day02;\nmod day10;\nmod day11;\n// marker\n\nfn main() {}\n"
+        );
+        assert!(insert_line(content, "// marker", "mod day10;").is_err());
+        assert!(insert_line(content, "// other", "mod day11;").is_err());
+        assert!(insert_line(content, "// marker", "    day11();").is_err());
+    }
+
+    #[test]
+    fn creates_new_year() {
+        let src = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&src);
+        fs::create_dir_all(src.join("year2023")).unwrap();
+        fs::write(src.join("lib.rs"), include_str!("lib.rs")).unwrap();
+        fs::write(src.join("year2023/mod.rs"), include_str!("year2023/mod.rs")).unwrap();
+
+        new_day(&src, 2024, 3).unwrap();
+        new_day(&src, 2024, 1).unwrap();
+        assert!(new_day(&src, 2024, 1).is_err());
+        assert!(new_day(&src, 2024, 26).is_err());
+
+        let lib = fs::read_to_string(src.join("lib.rs")).unwrap();
+        assert!(lib.contains("mod year2023;\nmod year2024;\n"));
+        assert!(lib.contains(
+            "    year2023::register(&mut registry);\n    year2024::register(&mut registry);\n"
+        ));
+
+        let year_mod = fs::read_to_string(src.join("year2024/mod.rs")).unwrap();
+        assert!(year_mod.starts_with("mod day01;\nmod day03;\n"));
+        assert!(year_mod.contains("pub const YEAR: u16 = 2024;"));
+        assert!(year_mod.contains("    registry.insert((YEAR, 1), Box::new(day01::Day01));\n    registry.insert((YEAR, 3), Box::new(day03::Day03));\n"));
+
+        let day = fs::read_to_string(src.join("year2024/day03/mod.rs")).unwrap();
+        assert!(day.contains("impl Solver for Day03 {"));
+        assert!(day.contains("ParseContext::new(3, input_str)"));
+        assert_eq!(day.matches("#[ignore = ").count(), 2);
+        assert!(src.join("year2024/day03/example_input.txt").is_file());
+
+        fs::remove_dir_all(src).unwrap();
+    }
+}
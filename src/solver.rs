@@ -0,0 +1,202 @@
+use std::any::Any;
+use std::fmt;
+
+use rand::rngs::StdRng;
+
+use crate::{AocError, Artifact, Stepper};
+
+/// typed answer of a single puzzle part
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    None, // part has no puzzle (day 25 part 2)
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+impl Answer {
+    /// name of the variant, used in machine readable output
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Text(_) => "text",
+            Answer::None => "none",
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Unsigned, u128, u32, u64, u128, usize);
+answer_from!(Signed, i128, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// a single day's puzzle: parse the input once, then solve both parts from the parsed input
+pub trait Solver {
+    type Input;
+
+    /// sentence printed for part 1, "{}" is replaced by the answer
+    const PART_ONE: &'static str;
+    /// sentence printed for part 2, "{}" is replaced by the answer
+    const PART_TWO: &'static str;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError>;
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError>;
+
+    /// random input in this day's format for stress testing, None if the day has no generator
+    /// it has to meet everything the solver assumes about real inputs, `size` (at least 1) scales it
+    fn generate(&self, _rng: &mut StdRng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// input text that parses back into the same input, None if the day can't write its input
+    fn serialize(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// answer to `part` from a slow but obviously correct solver, to check the real one against on small inputs
+    /// None if there is no reference solver for the part, days only have them with the `reference` feature
+    fn reference(&self, _input: &Self::Input, _part: u8) -> Option<Result<Answer, AocError>> {
+        None
+    }
+
+    /// files showing how the input was solved, like maps or traces, only computed when asked for with `--emit`
+    fn emit(&self, _input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(vec![])
+    }
+
+    /// the simulation behind the answers for `aoc view` to step through, None if the day doesn't simulate anything
+    fn stepper(&self, _input: &Self::Input) -> Option<Box<dyn Stepper>> {
+        None
+    }
+}
+
+/// object safe version of `Solver` so days with different input types can live in one registry
+/// implemented for every `Solver`, the parsed input is passed around as `dyn Any`
+/// solvers are shared between threads when running days in parallel, so they must be `Send + Sync`
+pub trait DynSolver: Send + Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError>;
+    fn part_one(&self, input: &dyn Any) -> Result<Answer, AocError>;
+    fn part_two(&self, input: &dyn Any) -> Result<Answer, AocError>;
+    fn describe(&self, part: u8, answer: &Answer) -> String;
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String>;
+    fn serialize(&self, input: &dyn Any) -> Option<String>;
+    fn reference(&self, input: &dyn Any, part: u8) -> Option<Result<Answer, AocError>>;
+    fn emit(&self, input: &dyn Any) -> Result<Vec<Artifact>, AocError>;
+    fn stepper(&self, input: &dyn Any) -> Option<Box<dyn Stepper>>;
+}
+
+impl<S> DynSolver for S
+where
+    S: Solver + Send + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(Solver::parse(self, input)?))
+    }
+
+    fn part_one(&self, input: &dyn Any) -> Result<Answer, AocError> {
+        Solver::part_one(self, downcast::<S>(input))
+    }
+
+    fn part_two(&self, input: &dyn Any) -> Result<Answer, AocError> {
+        Solver::part_two(self, downcast::<S>(input))
+    }
+
+    fn describe(&self, part: u8, answer: &Answer) -> String {
+        let sentence = match part {
+            1 => S::PART_ONE,
+            _ => S::PART_TWO,
+        };
+        sentence.replace("{}", &answer.to_string())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Solver::generate(self, rng, size)
+    }
+
+    fn serialize(&self, input: &dyn Any) -> Option<String> {
+        Solver::serialize(self, downcast::<S>(input))
+    }
+
+    fn reference(&self, input: &dyn Any, part: u8) -> Option<Result<Answer, AocError>> {
+        Solver::reference(self, downcast::<S>(input), part)
+    }
+
+    fn emit(&self, input: &dyn Any) -> Result<Vec<Artifact>, AocError> {
+        Solver::emit(self, downcast::<S>(input))
+    }
+
+    fn stepper(&self, input: &dyn Any) -> Option<Box<dyn Stepper>> {
+        Solver::stepper(self, downcast::<S>(input))
+    }
+}
+
+/// parses `input` and writes it back, which must give text that parses and writes back to itself
+/// inputs that don't parse are fine, the parser has to reject them with an error rather than panic
+pub fn check_round_trip(solver: &dyn DynSolver, input: &str) -> Result<(), String> {
+    let Ok(parsed) = solver.parse(input) else {
+        return Ok(());
+    };
+    let Some(serialized) = solver.serialize(parsed.as_ref()) else {
+        return Ok(());
+    };
+    let reparsed = solver
+        .parse(&serialized)
+        .map_err(|err| format!("written input doesn't parse: {err}\n{serialized}"))?;
+    match solver.serialize(reparsed.as_ref()) {
+        Some(again) if again == serialized => Ok(()),
+        again => Err(format!(
+            "writing the input again gives something else:\n{serialized}\n---\n{}",
+            again.unwrap_or_default()
+        )),
+    }
+}
+
+/// parses `input`, which has to either work and write back as in `check_round_trip` or fail with `AocError::Parse`
+/// any other error means the parser let through something the solver can't use
+pub fn check_parse(solver: &dyn DynSolver, input: &str) -> Result<(), String> {
+    if let Err(err) = solver.parse(input) {
+        if !matches!(err, AocError::Parse { .. }) {
+            return Err(format!("input is rejected without a parse error: {err}"));
+        }
+    }
+    check_round_trip(solver, input)
+}
+
+fn downcast<S: Solver>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input was produced by a different solver!")
+}
@@ -0,0 +1,254 @@
+use std::io::{self, Write};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute, queue};
+
+/// a simulation that can be looked at state by state, step 0 being the state before anything happened
+/// steps can be asked for in any order, so viewers can go back and jump around
+pub trait Stepper {
+    /// number of the last step, None if the simulation goes on forever
+    fn last_step(&self) -> Option<usize>;
+
+    /// the state after `step` steps, never asked for steps after `last_step`
+    fn frame(&mut self, step: usize) -> Frame;
+}
+
+/// what a stepper shows of one state
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// the state drawn as text, like the maps in the puzzle descriptions
+    pub view: String,
+    /// numbers worth following from step to step, like the load on the platform
+    pub counters: Vec<(&'static str, u64)>,
+}
+
+impl Frame {
+    /// "load 136, same as spin 0"
+    pub fn counter_line(&self) -> String {
+        let counters: Vec<String> = self
+            .counters
+            .iter()
+            .map(|(name, value)| format!("{name} {value}"))
+            .collect();
+        counters.join(", ")
+    }
+}
+
+const HELP: &str = "←/→ step  PgUp/PgDn 10 steps  Home/End ends  g go to step  ↑/↓ scroll  q quit";
+
+/// what the viewer is showing, apart from the terminal so the keys can be handled without one
+struct Viewer {
+    step: usize,
+    last_step: Option<usize>,
+    /// first line of the view on screen, for views taller than the terminal
+    scroll: usize,
+    /// digits typed after `g`
+    jump: Option<String>,
+}
+
+impl Viewer {
+    /// false once the viewer should close
+    fn handle(&mut self, key: KeyCode) -> bool {
+        if let Some(digits) = &mut self.jump {
+            match key {
+                KeyCode::Char(digit) if digit.is_ascii_digit() => digits.push(digit),
+                KeyCode::Backspace => {
+                    digits.pop();
+                }
+                KeyCode::Enter => {
+                    if let Ok(step) = digits.parse() {
+                        self.go_to(step);
+                    }
+                    self.jump = None;
+                }
+                _ => self.jump = None,
+            }
+            return true;
+        }
+
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+                self.go_to(self.step.saturating_add(1))
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.go_to(self.step.saturating_sub(1)),
+            KeyCode::PageDown => self.go_to(self.step.saturating_add(10)),
+            KeyCode::PageUp => self.go_to(self.step.saturating_sub(10)),
+            KeyCode::Home => self.go_to(0),
+            KeyCode::End => {
+                if let Some(last_step) = self.last_step {
+                    self.go_to(last_step);
+                }
+            }
+            KeyCode::Char('g') => self.jump = Some(String::new()),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+        true
+    }
+
+    /// steps after the last one show the last one
+    fn go_to(&mut self, step: usize) {
+        self.step = match self.last_step {
+            Some(last_step) => step.min(last_step),
+            None => step,
+        };
+    }
+
+    /// "step 3/10" or "step 3/∞"
+    fn position(&self) -> String {
+        match self.last_step {
+            Some(last_step) => format!("step {}/{last_step}", self.step),
+            None => format!("step {}/∞", self.step),
+        }
+    }
+}
+
+/// lets the user step through `stepper` in the terminal until they quit, starting at `step`
+pub fn view(title: &str, stepper: &mut dyn Stepper, step: usize) -> io::Result<()> {
+    let mut viewer = Viewer {
+        step: 0,
+        last_step: stepper.last_step(),
+        scroll: 0,
+        jump: None,
+    };
+    viewer.go_to(step);
+
+    terminal::enable_raw_mode()?;
+    let _screen = Screen;
+    let mut stdout = io::stdout();
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    show(title, stepper, &mut viewer, &mut stdout)
+}
+
+/// puts the terminal back the way it was when dropped, also when drawing fails or a stepper panics
+struct Screen;
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn show(
+    title: &str,
+    stepper: &mut dyn Stepper,
+    viewer: &mut Viewer,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    loop {
+        let frame = stepper.frame(viewer.step);
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        // header, counters and an empty line above the view, an empty line and the help below it
+        let view_height = height.saturating_sub(5).max(1);
+        let lines: Vec<&str> = frame.view.lines().collect();
+        viewer.scroll = viewer.scroll.min(lines.len().saturating_sub(view_height));
+
+        let header = match &viewer.jump {
+            Some(digits) => format!("{title}  {}  jump to step: {digits}_", viewer.position()),
+            None => format!("{title}  {}", viewer.position()),
+        };
+        queue!(
+            out,
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0),
+            SetAttribute(Attribute::Bold),
+            Print(clip(&header, width)),
+            SetAttribute(Attribute::Reset),
+            cursor::MoveTo(0, 1),
+            Print(clip(&frame.counter_line(), width)),
+        )?;
+        for (row, line) in lines
+            .iter()
+            .skip(viewer.scroll)
+            .take(view_height)
+            .enumerate()
+        {
+            queue!(
+                out,
+                cursor::MoveTo(0, 3 + row as u16),
+                Print(clip(line, width))
+            )?;
+        }
+        queue!(
+            out,
+            cursor::MoveTo(0, height.saturating_sub(1) as u16),
+            SetAttribute(Attribute::Dim),
+            Print(clip(HELP, width)),
+            SetAttribute(Attribute::Reset),
+        )?;
+        out.flush()?;
+
+        // resizing just draws again
+        if let Event::Key(KeyEvent {
+            code,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        {
+            if !viewer.handle(code) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// the first `width` characters of `line`, lines running off the right edge would wrap and mess up the screen
+fn clip(line: &str, width: usize) -> String {
+    line.chars().take(width).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewer(last_step: Option<usize>) -> Viewer {
+        Viewer {
+            step: 0,
+            last_step,
+            scroll: 0,
+            jump: None,
+        }
+    }
+
+    #[test]
+    fn steps_stay_in_range() {
+        let mut viewer = viewer(Some(12));
+        viewer.handle(KeyCode::Left);
+        assert_eq!(viewer.step, 0);
+        viewer.handle(KeyCode::PageDown);
+        viewer.handle(KeyCode::Right);
+        assert_eq!(viewer.position(), "step 11/12");
+        viewer.handle(KeyCode::PageDown);
+        assert_eq!(viewer.step, 12);
+        viewer.handle(KeyCode::Home);
+        assert_eq!(viewer.step, 0);
+        viewer.handle(KeyCode::End);
+        assert_eq!(viewer.step, 12);
+        assert!(!viewer.handle(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn jumps_to_typed_step() {
+        let mut viewer = viewer(None);
+        for key in ['g', '1', '0', '0', '0'] {
+            viewer.handle(KeyCode::Char(key));
+        }
+        viewer.handle(KeyCode::Backspace);
+        assert_eq!(viewer.jump.as_deref(), Some("100"));
+        viewer.handle(KeyCode::Enter);
+        assert_eq!(viewer.position(), "step 100/∞");
+        viewer.handle(KeyCode::End);
+        assert_eq!(viewer.step, 100);
+
+        // q while typing cancels the jump instead of quitting
+        viewer.handle(KeyCode::Char('g'));
+        assert!(viewer.handle(KeyCode::Char('q')));
+        assert_eq!((viewer.step, viewer.jump.as_ref()), (100, None));
+    }
+}
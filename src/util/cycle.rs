@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use num::Integer;
+
+/// shape of a sequence `start, step(start), step(step(start)), ...` that ends up going in circles:
+/// `tail` states before the first repeating one, then the same `period` states over and over
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub tail: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// first index with the same state as index `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.tail {
+            n
+        } else {
+            self.tail + (n - self.tail) % self.period
+        }
+    }
+}
+
+/// Brent's algorithm, needs no memory besides two states
+pub fn brent<T: PartialEq + Clone>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // find the period by moving the tortoise to the hare at every power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // with the hare one period ahead, they meet at the start of the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut tail = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    Cycle { tail, period }
+}
+
+/// Floyd's tortoise and hare, like `brent` but with more steps
+pub fn floyd<T: PartialEq + Clone>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut tail = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { tail, period }
+}
+
+/// remembers every state, so `step` is only called `tail + period` times, best for expensive steps
+/// also returns those states, index `n` of the sequence is `states[cycle.reduce(n)]`
+pub fn hashing<T: Hash + Eq + Clone>(start: T, step: impl FnMut(&T) -> T) -> (Cycle, Vec<T>) {
+    match run(start, step, None) {
+        Run::Cycle(cycle, states) => (cycle, states),
+        Run::Reached(_) => unreachable!("no limit was given"),
+    }
+}
+
+/// state after `n` steps, skips over all full cycles once the first repetition is found
+pub fn state_at<T: Hash + Eq + Clone>(start: T, step: impl FnMut(&T) -> T, n: usize) -> T {
+    match run(start, step, Some(n)) {
+        Run::Cycle(cycle, mut states) => states.swap_remove(cycle.reduce(n)),
+        Run::Reached(state) => state,
+    }
+}
+
+enum Run<T> {
+    Cycle(Cycle, Vec<T>),
+    Reached(T),
+}
+
+/// steps until a state repeats, or until step `limit` is reached before that
+fn run<T: Hash + Eq + Clone>(
+    start: T,
+    mut step: impl FnMut(&T) -> T,
+    limit: Option<usize>,
+) -> Run<T> {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+
+    loop {
+        let idx = states.len();
+        if limit == Some(idx) {
+            return Run::Reached(state);
+        }
+        if let Some(&first) = seen.get(&state) {
+            let cycle = Cycle {
+                tail: first,
+                period: idx - first,
+            };
+            return Run::Cycle(cycle, states);
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), idx);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// something that happens at `offset`, `offset + period`, `offset + 2 * period` and so on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Periodic {
+    pub offset: u64,
+    pub period: u64,
+}
+
+/// when all the events happen at the same time, None if they never do
+/// this is the chinese remainder theorem, generalized to periods that aren't coprime
+/// with all offsets 0 (or equal to the periods) it comes down to the lcm of the periods
+pub fn combine(events: &[Periodic]) -> Option<Periodic> {
+    events.iter().try_fold(
+        Periodic {
+            offset: 0,
+            period: 1,
+        },
+        |combined, event| combine_two(combined, *event),
+    )
+}
+
+fn combine_two(a: Periodic, b: Periodic) -> Option<Periodic> {
+    assert!(a.period > 0 && b.period > 0, "periods must be positive");
+    let (a_offset, a_period) = (a.offset as i128, a.period as i128);
+    let (b_offset, b_period) = (b.offset as i128, b.period as i128);
+
+    // a_offset + k * a_period has to be b_offset modulo b_period
+    let gcd = a_period.extended_gcd(&b_period);
+    let difference = b_offset - a_offset;
+    if difference % gcd.gcd != 0 {
+        return None;
+    }
+    let modulus = b_period / gcd.gcd;
+    let k = ((difference / gcd.gcd).rem_euclid(modulus) * gcd.x.rem_euclid(modulus)) % modulus;
+
+    let period = a_period.lcm(&b_period);
+    let mut offset = a_offset + k * a_period;
+    // neither event happens before its offset
+    let earliest = a_offset.max(b_offset);
+    if offset < earliest {
+        offset += Integer::div_ceil(&(earliest - offset), &period) * period;
+    }
+
+    Some(Periodic {
+        offset: u64::try_from(offset).ok()?,
+        period: u64::try_from(period).ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 1 2 3 4 2 3 4 2 ...
+    fn step(state: &u32) -> u32 {
+        if *state == 4 {
+            2
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let expected = Cycle { tail: 2, period: 3 };
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(floyd(0, step), expected);
+        let (cycle, states) = hashing(0, step);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, vec![0, 1, 2, 3, 4]);
+
+        // no tail
+        let expected = Cycle { tail: 0, period: 7 };
+        let rotate = |state: &u32| (state + 3) % 7;
+        assert_eq!(brent(1, rotate), expected);
+        assert_eq!(floyd(1, rotate), expected);
+        assert_eq!(hashing(1, rotate).0, expected);
+    }
+
+    #[test]
+    fn fast_forwards() {
+        assert_eq!(state_at(0, step, 3), 3);
+        assert_eq!(state_at(0, step, 10), 4);
+        assert_eq!(state_at(0, step, 1_000_000_000_000), 4);
+    }
+
+    #[test]
+    fn combines() {
+        let event = |offset, period| Periodic { offset, period };
+        assert_eq!(combine(&[event(2, 3), event(3, 5)]), Some(event(8, 15)));
+        assert_eq!(combine(&[event(1, 4), event(3, 6)]), Some(event(9, 12)));
+        assert_eq!(combine(&[event(0, 4), event(1, 6)]), None);
+        assert_eq!(combine(&[event(10, 3), event(1, 1)]), Some(event(10, 3)));
+        assert_eq!(
+            combine(&[event(4, 4), event(6, 6), event(10, 10)]),
+            Some(event(60, 60))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+
+use crate::Artifact;
+
+/// a graph in the DOT language of Graphviz, e.g. drawn with `dot -Tsvg graph.dot > graph.svg`
+/// nodes and edges are styled with Graphviz attributes like `("shape", "box")` or `("label", "a<2006")`
+#[derive(Clone, Debug)]
+pub struct Dot {
+    directed: bool,
+    statements: Vec<String>,
+}
+
+impl Dot {
+    /// a graph with edges going one way, drawn as arrows
+    pub fn directed() -> Dot {
+        Dot {
+            directed: true,
+            statements: vec![],
+        }
+    }
+
+    /// a graph with edges going both ways, drawn as lines
+    pub fn undirected() -> Dot {
+        Dot {
+            directed: false,
+            statements: vec![],
+        }
+    }
+
+    /// attributes for all nodes added after this
+    pub fn node_defaults(&mut self, attributes: &[(&str, &str)]) {
+        self.statements
+            .push(format!("node{}", attribute_list(attributes)));
+    }
+
+    /// nodes only need to be added to style them, edges add the nodes they connect anyway
+    pub fn node(&mut self, id: &str, attributes: &[(&str, &str)]) {
+        self.statements
+            .push(format!("{}{}", quote(id), attribute_list(attributes)));
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attributes: &[(&str, &str)]) {
+        let arrow = if self.directed { "->" } else { "--" };
+        self.statements.push(format!(
+            "{} {arrow} {}{}",
+            quote(from),
+            quote(to),
+            attribute_list(attributes)
+        ));
+    }
+
+    pub fn artifact(&self, name: &str) -> Artifact {
+        Artifact::text(name, self.to_string())
+    }
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} {{", if self.directed { "digraph" } else { "graph" })?;
+        for statement in &self.statements {
+            writeln!(f, "  {statement};")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// every id and value is quoted, so names like "broadcaster" or "a<2006" need no special care
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// " [shape=\"box\", color=\"red\"]", nothing without attributes
+fn attribute_list(attributes: &[(&str, &str)]) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let attributes: Vec<String> = attributes
+        .iter()
+        .map(|(name, value)| format!("{name}={}", quote(value)))
+        .collect();
+    format!(" [{}]", attributes.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_nodes_and_edges() {
+        let mut dot = Dot::directed();
+        dot.node_defaults(&[("shape", "box")]);
+        dot.node("in", &[]);
+        dot.edge("in", "A", &[("label", "a<2006"), ("color", "green")]);
+        assert_eq!(
+            dot.to_string(),
+            "digraph {\n  node [shape=\"box\"];\n  \"in\";\n  \"in\" -> \"A\" [label=\"a<2006\", color=\"green\"];\n}\n"
+        );
+
+        let mut dot = Dot::undirected();
+        dot.edge("say \"hi\"", "x", &[]);
+        assert!(dot.to_string().contains("\"say \\\"hi\\\"\" -- \"x\";"));
+    }
+}
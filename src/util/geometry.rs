@@ -0,0 +1,211 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// one of the four orthogonal directions, on a map where rows grow downwards
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    /// clockwise, starting to the right
+    pub const ALL: [Direction; 4] = [
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Up,
+    ];
+
+    /// quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Up => Direction::Right,
+        }
+    }
+
+    /// quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Up => Direction::Down,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Right | Direction::Left)
+    }
+
+    /// one step in this direction, y grows downwards
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Up => Point::new(0, -1),
+        }
+    }
+
+    /// `R`, `D`, `L` or `U`
+    pub fn from_letter(c: char) -> Option<Self> {
+        Self::from_char(c, "RDLU")
+    }
+
+    /// `0` (right) to `3` (up), clockwise
+    pub fn from_digit(c: char) -> Option<Self> {
+        Self::from_char(c, "0123")
+    }
+
+    /// `>`, `v`, `<` or `^`
+    pub fn from_arrow(c: char) -> Option<Self> {
+        Self::from_char(c, ">v<^")
+    }
+
+    /// `chars` has the characters for right, down, left and up
+    fn from_char(c: char, chars: &str) -> Option<Self> {
+        chars
+            .chars()
+            .position(|candidate| candidate == c)
+            .map(|idx| Direction::ALL[idx])
+    }
+}
+
+/// point or vector on a map, x grows to the right and y downwards
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// point of a grid position (row, col)
+    pub fn from_position((row, col): (usize, usize)) -> Self {
+        Point::new(col as i64, row as i64)
+    }
+
+    /// grid position (row, col) of the point, if neither coordinate is negative
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    /// `distance` steps in `direction`
+    pub fn step(self, direction: Direction, distance: i64) -> Self {
+        self + direction.delta() * distance
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn checked_add(self, other: Point) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Point) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    pub fn checked_mul(self, factor: i64) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+        ))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().delta(), -direction.delta());
+        }
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn parses() {
+        assert_eq!(Direction::from_letter('L'), Some(Direction::Left));
+        assert_eq!(Direction::from_digit('1'), Some(Direction::Down));
+        assert_eq!(Direction::from_arrow('^'), Some(Direction::Up));
+        assert_eq!(Direction::from_letter('0'), None);
+        assert_eq!(Direction::from_arrow('V'), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let point = Point::new(2, 3).step(Direction::Up, 5);
+        assert_eq!(point, Point::new(2, -2));
+        assert_eq!(point.to_position(), None);
+        assert_eq!(Point::from_position((3, 2)), Point::new(2, 3));
+        assert_eq!(Point::new(2, 3).to_position(), Some((3, 2)));
+        assert_eq!(point.manhattan(Point::ORIGIN), 4);
+        assert_eq!(Point::new(i64::MAX, 0).checked_add(Point::new(1, 0)), None);
+        assert_eq!(Point::new(1, 1).checked_mul(3), Some(Point::new(3, 3)));
+    }
+}
@@ -0,0 +1,484 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// a graph that is only known through the neighbors of each node, so it can be generated on the fly
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// nodes reachable from `node` in one step, with the cost of that step
+    fn neighbors(&self, node: &Self::Node) -> Vec<(Self::Node, u64)>;
+}
+
+/// explicit graph, e.g. the result of `contract`, edges can be directed
+impl<N: Clone + Eq + Hash> Graph for HashMap<N, Vec<(N, u64)>> {
+    type Node = N;
+
+    fn neighbors(&self, node: &N) -> Vec<(N, u64)> {
+        self.get(node).cloned().unwrap_or_default()
+    }
+}
+
+/// cheapest way to a goal: its total cost and all nodes on the way, from start to goal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: u64,
+    pub nodes: Vec<N>,
+}
+
+/// a cut of an undirected graph: the edges to remove and the two parts that are left
+#[derive(Clone, Debug)]
+pub struct Cut<N> {
+    pub edges: Vec<(N, N)>,
+    pub sides: (Vec<N>, Vec<N>),
+}
+
+/// entry of the priority queue, the lowest estimate comes out first
+struct Queued<N> {
+    estimate: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed, the heap is a max heap
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+/// Dijkstra's algorithm, cheapest path from `start` to the first node that `is_goal`
+pub fn shortest_path<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    a_star(graph, start, is_goal, |_| 0)
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a goal
+pub fn a_star<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    let mut costs: HashMap<G::Node, u64> = HashMap::from([(start.clone(), 0)]);
+    let mut previous: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut heap = BinaryHeap::from([Queued {
+        estimate: heuristic(&start),
+        cost: 0,
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        if cost > costs[&node] {
+            // a cheaper way to this node was already handled
+            continue;
+        }
+        if is_goal(&node) {
+            let mut nodes = vec![node];
+            while let Some(before) = previous.get(nodes.last().unwrap()) {
+                nodes.push(before.clone());
+            }
+            nodes.reverse();
+            return Some(Path { cost, nodes });
+        }
+
+        for (neighbor, step_cost) in graph.neighbors(&node) {
+            let new_cost = cost + step_cost;
+            if costs.get(&neighbor).is_some_and(|&known| known <= new_cost) {
+                continue;
+            }
+            costs.insert(neighbor.clone(), new_cost);
+            previous.insert(neighbor.clone(), node.clone());
+            heap.push(Queued {
+                estimate: new_cost + heuristic(&neighbor),
+                cost: new_cost,
+                node: neighbor,
+            });
+        }
+    }
+
+    None
+}
+
+/// breadth first search, number of steps to every node reachable in at most `max_distance` steps
+/// the step costs are ignored
+pub fn bfs_distances<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    max_distance: Option<usize>,
+) -> HashMap<G::Node, usize> {
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        if max_distance.is_some_and(|max| distance >= max) {
+            continue;
+        }
+        for (neighbor, _) in graph.neighbors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(neighbor.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// replaces the corridors between `junctions` by single edges with the summed up cost
+/// a corridor ends at the next junction, or at a dead end (which drops it)
+pub fn contract<G: Graph>(
+    graph: &G,
+    junctions: &[G::Node],
+) -> HashMap<G::Node, Vec<(G::Node, u64)>> {
+    let is_junction: HashSet<&G::Node> = junctions.iter().collect();
+    let mut contracted = HashMap::new();
+
+    for junction in junctions {
+        let mut edges = vec![];
+
+        for first in graph.neighbors(junction) {
+            if let Some(mut corridor) =
+                corridor(graph, junction, first, |node| is_junction.contains(node))
+            {
+                let end = corridor
+                    .nodes
+                    .pop()
+                    .expect("a corridor has at least one node");
+                if end != *junction {
+                    edges.push((end, corridor.cost));
+                }
+            }
+        }
+
+        contracted.insert(junction.clone(), edges);
+    }
+
+    contracted
+}
+
+/// the nodes on the way from `junction` through its neighbor `first` (with the cost to get there) up to and
+/// including the next junction, None if the way ends before that, at a dead end or a fork that isn't a junction
+pub fn corridor<G: Graph>(
+    graph: &G,
+    junction: &G::Node,
+    (first, first_cost): (G::Node, u64),
+    is_junction: impl Fn(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut previous = junction.clone();
+    let mut nodes = vec![first];
+    let mut cost = first_cost;
+    let mut seen = HashSet::from([junction.clone()]);
+
+    loop {
+        let current = nodes.last().expect("starts with the first node").clone();
+        if is_junction(&current) {
+            return Some(Path { cost, nodes });
+        }
+        if !seen.insert(current.clone()) {
+            // went in circles without finding a junction
+            return None;
+        }
+
+        let mut next = graph
+            .neighbors(&current)
+            .into_iter()
+            .filter(|(node, _)| *node != previous);
+        match (next.next(), next.next()) {
+            (Some((node, step_cost)), None) => {
+                previous = current;
+                nodes.push(node);
+                cost += step_cost;
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// cost of the most expensive path from `start` to `goal` that doesn't visit a node twice
+/// this tries every path, so it's only feasible for small graphs, e.g. contracted ones
+pub fn longest_path<G: Graph>(graph: &G, start: G::Node, goal: &G::Node) -> Option<u64> {
+    fn search<G: Graph>(
+        graph: &G,
+        node: &G::Node,
+        goal: &G::Node,
+        visited: &mut HashSet<G::Node>,
+    ) -> Option<u64> {
+        if node == goal {
+            return Some(0);
+        }
+
+        let mut longest = None;
+        for (neighbor, cost) in graph.neighbors(node) {
+            if !visited.insert(neighbor.clone()) {
+                continue;
+            }
+            // None (no way to the goal from here) is smaller than any cost
+            longest = longest.max(search(graph, &neighbor, goal, visited).map(|rest| cost + rest));
+            visited.remove(&neighbor);
+        }
+        longest
+    }
+
+    let mut visited = HashSet::from([start.clone()]);
+    search(graph, &start, goal, &mut visited)
+}
+
+/// like `longest_path`, but with the nodes on the way
+/// they're found in a second search for a path of that cost, so the search for the cost doesn't keep track of them
+pub fn longest_route<G: Graph>(graph: &G, start: G::Node, goal: &G::Node) -> Option<Path<G::Node>> {
+    fn search<G: Graph>(
+        graph: &G,
+        nodes: &mut Vec<G::Node>,
+        cost_left: u64,
+        goal: &G::Node,
+        visited: &mut HashSet<G::Node>,
+    ) -> bool {
+        let node = nodes.last().expect("the route starts at the start");
+        if node == goal {
+            return cost_left == 0;
+        }
+
+        for (neighbor, cost) in graph.neighbors(node) {
+            if cost > cost_left || !visited.insert(neighbor.clone()) {
+                continue;
+            }
+            nodes.push(neighbor.clone());
+            if search(graph, nodes, cost_left - cost, goal, visited) {
+                return true;
+            }
+            nodes.pop();
+            visited.remove(&neighbor);
+        }
+        false
+    }
+
+    let cost = longest_path(graph, start.clone(), goal)?;
+    let mut visited = HashSet::from([start.clone()]);
+    let mut nodes = vec![start];
+    search(graph, &mut nodes, cost, goal, &mut visited).then_some(Path { cost, nodes })
+}
+
+/// minimum cut of the undirected graph with these edges, using Karger's algorithm
+/// that is random, so it's repeated `attempts` times or until a cut of at most `good_enough` edges is found
+/// the smallest cut found is returned, None if there are less than two nodes
+pub fn min_cut<N: Clone + Eq + Hash>(
+    edges: &[(N, N)],
+    good_enough: usize,
+    attempts: usize,
+) -> Option<Cut<N>> {
+    let mut nodes: Vec<N> = vec![];
+    let mut index: HashMap<N, usize> = HashMap::new();
+    let mut indexed_edges = vec![];
+    for (a, b) in edges {
+        let mut idx = |node: &N| {
+            *index.entry(node.clone()).or_insert_with(|| {
+                nodes.push(node.clone());
+                nodes.len() - 1
+            })
+        };
+        indexed_edges.push((idx(a), idx(b)));
+    }
+    if nodes.len() < 2 {
+        return None;
+    }
+
+    let cut_of = |sets: &mut UnionFind| {
+        let edges = indexed_edges
+            .iter()
+            .filter(|&&(a, b)| sets.find(a) != sets.find(b))
+            .map(|&(a, b)| (nodes[a].clone(), nodes[b].clone()))
+            .collect();
+        let side = sets.find(0);
+        let (left, right) = (0..nodes.len()).partition::<Vec<_>, _>(|&idx| sets.find(idx) == side);
+        Cut {
+            edges,
+            sides: (
+                left.into_iter().map(|idx| nodes[idx].clone()).collect(),
+                right.into_iter().map(|idx| nodes[idx].clone()).collect(),
+            ),
+        }
+    };
+
+    // an unconnected graph doesn't need cutting, and contraction couldn't get it down to two parts
+    let mut components = UnionFind::new(nodes.len());
+    for &(a, b) in indexed_edges.iter() {
+        components.union(a, b);
+    }
+    if components.count > 1 {
+        // the component of the first node against all others
+        let side = components.find(0);
+        let other = (0..nodes.len())
+            .find(|&idx| components.find(idx) != side)
+            .unwrap();
+        let mut parts = UnionFind::new(nodes.len());
+        for idx in 0..nodes.len() {
+            let root = if components.find(idx) == side {
+                0
+            } else {
+                other
+            };
+            parts.union(root, idx);
+        }
+        return Some(cut_of(&mut parts));
+    }
+
+    let mut best: Option<Cut<N>> = None;
+    for _ in 0..attempts {
+        // contract random edges until only two super nodes are left
+        let mut sets = UnionFind::new(nodes.len());
+        while sets.count > 2 {
+            let (a, b) = indexed_edges[rand::random::<usize>() % indexed_edges.len()];
+            sets.union(a, b);
+        }
+
+        let cut = cut_of(&mut sets);
+        if best
+            .as_ref()
+            .is_none_or(|best| cut.edges.len() < best.edges.len())
+        {
+            let done = cut.edges.len() <= good_enough;
+            best = Some(cut);
+            if done {
+                break;
+            }
+        }
+    }
+
+    best
+}
+
+/// disjoint sets of the numbers below `count`, with union by rank and path compression
+struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u32>,
+    /// number of sets
+    count: usize,
+}
+
+impl UnionFind {
+    fn new(count: usize) -> Self {
+        UnionFind {
+            parents: (0..count).collect(),
+            ranks: vec![0; count],
+            count,
+        }
+    }
+
+    fn find(&mut self, idx: usize) -> usize {
+        let parent = self.parents[idx];
+        if parent == idx {
+            return idx;
+        }
+        let root = self.find(parent);
+        self.parents[idx] = root;
+        root
+    }
+
+    /// merges the sets of `a` and `b`, attaching the lower ranked tree below the other
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        match self.ranks[a].cmp(&self.ranks[b]) {
+            Ordering::Less => self.parents[a] = b,
+            Ordering::Greater => self.parents[b] = a,
+            Ordering::Equal => {
+                self.parents[a] = b;
+                self.ranks[b] += 1;
+            }
+        }
+        self.count -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 - 1 - 2 - 3
+    ///      \     /
+    ///       4 - 5
+    fn example() -> HashMap<u32, Vec<(u32, u64)>> {
+        let mut graph: HashMap<u32, Vec<(u32, u64)>> = HashMap::new();
+        for (a, b, cost) in [
+            (0, 1, 1),
+            (1, 2, 5),
+            (2, 3, 1),
+            (1, 4, 1),
+            (4, 5, 1),
+            (5, 3, 1),
+        ] {
+            graph.entry(a).or_default().push((b, cost));
+            graph.entry(b).or_default().push((a, cost));
+        }
+        graph
+    }
+
+    #[test]
+    fn finds_shortest_path() {
+        let graph = example();
+        let path = shortest_path(&graph, 0, |&node| node == 3).unwrap();
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes, vec![0, 1, 4, 5, 3]);
+        assert_eq!(a_star(&graph, 0, |&node| node == 3, |_| 1).unwrap().cost, 4);
+        assert_eq!(shortest_path(&graph, 0, |&node| node == 7), None);
+    }
+
+    #[test]
+    fn measures_distances() {
+        let distances = bfs_distances(&example(), 0, None);
+        assert_eq!(distances[&3], 3);
+        assert_eq!(distances.len(), 6);
+        assert_eq!(bfs_distances(&example(), 0, Some(2)).len(), 4);
+    }
+
+    #[test]
+    fn contracts_and_finds_longest_path() {
+        let graph = example();
+        let contracted = contract(&graph, &[0, 1, 3]);
+        assert_eq!(contracted[&0], vec![(1, 1)]);
+        let mut from_one = contracted[&1].clone();
+        from_one.sort();
+        assert_eq!(from_one, vec![(0, 1), (3, 3), (3, 6)]);
+
+        assert_eq!(longest_path(&contracted, 0, &3), Some(7));
+        assert_eq!(longest_path(&graph, 0, &3), Some(7));
+        assert_eq!(
+            longest_route(&contracted, 0, &3).unwrap().nodes,
+            vec![0, 1, 3]
+        );
+    }
+
+    #[test]
+    fn cuts() {
+        // two triangles joined by one edge
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)];
+        let cut = min_cut(&edges, 1, 1000).unwrap();
+        assert_eq!(cut.edges, vec![(2, 3)]);
+        assert_eq!((cut.sides.0.len(), cut.sides.1.len()), (3, 3));
+
+        let cut = min_cut(&[(0, 1), (2, 3)], 1, 1000).unwrap();
+        assert!(cut.edges.is_empty());
+        assert_eq!((cut.sides.0.len(), cut.sides.1.len()), (2, 2));
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::util::geometry::Direction;
+use crate::{AocError, ParseContext};
+
+/// offsets (row, col) of the 4 orthogonal neighbors: right, down, left, up
+const NEIGHBORS_4: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+/// offsets (row, col) of all 8 neighbors, row by row
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// rectangular grid stored row by row in one flat vec, positions are (row, col)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// `cells` row by row, its length has to be `width * height`
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid size doesn't match");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// one cell per character, `cell` maps a character to its cell or to the message describing what was expected
+    /// lines have to be equally long, `what` describes the grid for the error on empty input
+    pub fn parse(
+        ctx: &ParseContext,
+        text: &str,
+        what: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, AocError> {
+        let width = text.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ctx.error(text, format!("expected {what}")));
+        }
+
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in text.lines() {
+            if line.chars().count() != width {
+                return Err(ctx.error(line, format!("expected {width} tiles like the first line")));
+            }
+            for (idx, c) in line.char_indices() {
+                cells.push(cell(c).map_err(|message| ctx.error(&line[idx..], message))?);
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if self.contains(row, col) {
+            Some(&self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.contains(row, col) {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// position moved by (`d_row`, `d_col`), if that's still inside the grid
+    pub fn offset(
+        &self,
+        (row, col): (usize, usize),
+        (d_row, d_col): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+        self.contains(row, col).then_some((row, col))
+    }
+
+    /// neighboring position in `direction`, if that's still inside the grid
+    pub fn step(&self, position: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let delta = direction.delta();
+        self.offset(position, (delta.y as isize, delta.x as isize))
+    }
+
+    /// toroidal access: the grid repeats infinitely in every direction
+    pub fn get_wrapping(&self, row: i64, col: i64) -> &T {
+        let row = row.rem_euclid(self.height as i64) as usize;
+        let col = col.rem_euclid(self.width as i64) as usize;
+        &self[(row, col)]
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// all positions row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// all cells with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// position of the first cell (row by row) equal to `value`
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// position of the first cell (row by row) matching `predicate`
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// orthogonal neighbors inside the grid
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// orthogonal and diagonal neighbors inside the grid
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// builds a grid of the given size from the cell at each position
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// quarter turn clockwise, the first column becomes the first row (reversed)
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    /// quarter turn counterclockwise, the last column becomes the first row
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+
+    /// mirrors left and right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(row, self.width - 1 - col)].clone()
+        })
+    }
+
+    /// mirrors top and bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(self.height - 1 - row, col)].clone()
+        })
+    }
+
+    /// one line per row, with one character per cell
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// grid of characters, each of which has to be one of `allowed`
+    pub fn parse_chars(
+        ctx: &ParseContext,
+        text: &str,
+        allowed: &str,
+        what: &str,
+    ) -> Result<Self, AocError> {
+        Grid::parse(ctx, text, what, |c| {
+            if allowed.contains(c) {
+                Ok(c)
+            } else {
+                Err(format!("expected {}", one_of(allowed)))
+            }
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            self.contains(row, col),
+            "({row}, {col}) is outside the grid"
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            self.contains(row, col),
+            "({row}, {col}) is outside the grid"
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// "'.', '#' or 'O'" for ".#O"
+fn one_of(chars: &str) -> String {
+    let quoted: Vec<String> = chars.chars().map(|c| format!("{c:?}")).collect();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {last}", rest.join(", ")),
+        _ => quoted.concat(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(&ParseContext::new(1, text), text, "a grid", Ok).unwrap()
+    }
+
+    #[test]
+    fn parses() {
+        let input = "#.\n.#\n";
+        let ctx = ParseContext::new(1, input);
+        let parsed = Grid::parse_chars(&ctx, input, ".#", "a grid").unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (2, 2));
+        assert_eq!(parsed[(0, 0)], '#');
+        assert_eq!(parsed.to_string(), input);
+
+        let input = "#.\n.O\n";
+        let ctx = ParseContext::new(1, input);
+        match Grid::parse_chars(&ctx, input, ".#", "a grid") {
+            Err(AocError::Parse {
+                line,
+                column,
+                message,
+                ..
+            }) => {
+                assert_eq!((line, column), (2, 2));
+                assert_eq!(message, "expected '.' or '#'");
+            }
+            result => panic!("unexpected {result:?}"),
+        }
+
+        let ctx = ParseContext::new(1, "");
+        assert!(Grid::parse_chars(&ctx, "##\n#\n", ".#", "a grid").is_err());
+        assert!(Grid::parse_chars(&ctx, "", ".#", "a grid").is_err());
+    }
+
+    #[test]
+    fn transforms() {
+        let abc = grid("abc\ndef\n");
+        assert_eq!(abc.transpose(), grid("ad\nbe\ncf\n"));
+        assert_eq!(abc.rotate_clockwise(), grid("da\neb\nfc\n"));
+        assert_eq!(abc.rotate_counterclockwise(), grid("cf\nbe\nad\n"));
+        assert_eq!(abc.flip_horizontal(), grid("cba\nfed\n"));
+        assert_eq!(abc.flip_vertical(), grid("def\nabc\n"));
+        assert_eq!(abc.rotate_clockwise().rotate_counterclockwise(), abc);
+    }
+
+    #[test]
+    fn views_and_lookups() {
+        let abc = grid("abc\ndef\n");
+        assert_eq!(abc.row(1), &['d', 'e', 'f']);
+        assert_eq!(abc.column(1).collect::<String>(), "be");
+        assert_eq!(abc.columns().count(), 3);
+        assert_eq!(abc.find(&'f'), Some((1, 2)));
+        assert_eq!(abc.find(&'x'), None);
+        assert_eq!(abc.get(2, 0), None);
+        assert_eq!(*abc.get_wrapping(-1, 3), 'd');
+        assert_eq!(*abc.get_wrapping(5, -4), 'f');
+    }
+
+    #[test]
+    fn neighbors() {
+        let abc = grid("abc\ndef\n");
+        assert_eq!(
+            abc.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            abc.neighbors8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+        assert_eq!(abc.step((0, 1), Direction::Down), Some((1, 1)));
+        assert_eq!(abc.step((0, 1), Direction::Up), None);
+    }
+}
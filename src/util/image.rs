@@ -0,0 +1,161 @@
+use crate::util::grid::Grid;
+use crate::Artifact;
+
+/// red, green and blue
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GRAY: Rgb = [110, 110, 110];
+pub const DARK_GRAY: Rgb = [40, 40, 40];
+pub const RED: Rgb = [220, 50, 47];
+pub const GREEN: Rgb = [90, 200, 60];
+pub const BLUE: Rgb = [40, 110, 220];
+pub const YELLOW: Rgb = [250, 210, 50];
+pub const ORANGE: Rgb = [240, 140, 30];
+
+/// how `--emit` writes images
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ImageFormat {
+    Png,
+    Ppm,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Ppm => "ppm",
+        }
+    }
+
+    pub fn encode(self, image: &Image) -> Vec<u8> {
+        match self {
+            ImageFormat::Png => image.png(),
+            ImageFormat::Ppm => image.ppm(),
+        }
+    }
+}
+
+/// picture of a grid, every cell drawn as a square of pixels in the color the palette gives it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// each cell becomes `scale` by `scale` pixels
+    pub fn render<T>(grid: &Grid<T>, scale: usize, palette: impl Fn(&T) -> Rgb) -> Image {
+        let scale = scale.max(1);
+        let (width, height) = (grid.width() * scale, grid.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in grid.rows() {
+            let colors: Vec<Rgb> = row
+                .iter()
+                .flat_map(|cell| [palette(cell)].repeat(scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&colors);
+            }
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// color of the pixel `x` from the left and `y` from the top
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// binary PPM, big but simple enough to be read by about anything
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    pub fn png(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut encoder = png::Encoder::new(&mut bytes, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.pixels.concat()))
+            .expect("writing into memory can't fail");
+        bytes
+    }
+
+    /// `name` without an extension, the image is only encoded when written, in the format of the run
+    pub fn artifact(self, name: &str) -> Artifact {
+        Artifact::image(name, self)
+    }
+}
+
+/// the steps of an animation as numbered files, "beam" becomes "beam-00", "beam-01", ...
+/// the numbers are padded so the files sort in order
+pub fn frames(name: &str, images: Vec<Image>) -> Vec<Artifact> {
+    let digits = images.len().saturating_sub(1).to_string().len();
+    images
+        .into_iter()
+        .enumerate()
+        .map(|(i, image)| image.artifact(&format!("{name}-{i:0digits$}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkers() -> Image {
+        let grid = Grid::new(3, 2, vec![true, false, true, false, true, false]);
+        Image::render(&grid, 2, |&white| if white { WHITE } else { BLACK })
+    }
+
+    #[test]
+    fn scales_cells_to_squares() {
+        let image = checkers();
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.pixel(1, 1), WHITE);
+        assert_eq!(image.pixel(2, 1), BLACK);
+        assert_eq!(image.pixel(3, 2), WHITE);
+    }
+
+    #[test]
+    fn encodes_ppm_and_png() {
+        let image = checkers();
+        let ppm = image.ppm();
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 6 * 4 * 3);
+
+        let png = image.png();
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut decoded = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut decoded).unwrap();
+        assert_eq!(decoded, image.pixels.concat());
+    }
+
+    #[test]
+    fn numbers_frames_in_order() {
+        let names: Vec<String> = frames("spin", vec![checkers(); 11])
+            .into_iter()
+            .map(|artifact| artifact.name)
+            .collect();
+        assert_eq!(names[0], "spin-00");
+        assert_eq!(names[10], "spin-10");
+    }
+}
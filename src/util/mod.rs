@@ -0,0 +1,9 @@
+/// helpers shared by the puzzles of all years
+pub mod cycle;
+pub mod dot;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod parse;
+pub mod svg;
@@ -0,0 +1,20 @@
+use std::str::FromStr;
+
+use crate::{AocError, ParseContext};
+
+/// whitespace separated numbers, e.g. "79 14 55 13"
+pub fn numbers<T: FromStr>(ctx: &ParseContext, text: &str) -> Result<Vec<T>, AocError> {
+    text.split_whitespace().map(|nr| ctx.number(nr)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers() {
+        let ctx = ParseContext::new(1, "");
+        assert_eq!(numbers::<i32>(&ctx, " 1 -2  3 "), Ok(vec![1, -2, 3]));
+        assert!(numbers::<u8>(&ctx, "1 x").is_err());
+    }
+}
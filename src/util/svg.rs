@@ -0,0 +1,147 @@
+use std::fmt;
+
+use crate::Artifact;
+
+/// longest side of the drawing in pixels, it's a vector image so viewers can zoom in anyway
+const SIZE: f64 = 1000.0;
+
+/// a drawing in the coordinates of the puzzle, with y pointing down like on the grids
+/// styles are CSS like "fill:none;stroke:red", lines keep their width in pixels however large the coordinates are
+#[derive(Clone, Debug)]
+pub struct Svg {
+    min: (f64, f64),
+    size: (f64, f64),
+    elements: Vec<String>,
+}
+
+impl Svg {
+    /// shows everything from `min` to `max`, with a small margin around it
+    pub fn new(min: (f64, f64), max: (f64, f64)) -> Svg {
+        let margin = (max.0 - min.0).max(max.1 - min.1).max(1.0) / 20.0;
+        Svg {
+            min: (min.0 - margin, min.1 - margin),
+            size: (max.0 - min.0 + 2.0 * margin, max.1 - min.1 + 2.0 * margin),
+            elements: vec![],
+        }
+    }
+
+    /// length in the coordinates of the drawing that ends up as `pixels` pixels
+    pub fn pixels(&self, pixels: f64) -> f64 {
+        pixels * self.size.0.max(self.size.1) / SIZE
+    }
+
+    pub fn polygon(&mut self, points: &[(f64, f64)], style: &str) {
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+        self.add(format!(
+            r#"<polygon points="{}" style="{}"/>"#,
+            points.join(" "),
+            style_of(style)
+        ));
+    }
+
+    pub fn line(&mut self, from: (f64, f64), to: (f64, f64), style: &str) {
+        self.add(format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" style="{}"/>"#,
+            from.0,
+            from.1,
+            to.0,
+            to.1,
+            style_of(style)
+        ));
+    }
+
+    /// rectangle from the top left corner `min` to the bottom right corner `max`
+    pub fn rect(&mut self, min: (f64, f64), max: (f64, f64), style: &str) {
+        self.add(format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" style="{}"/>"#,
+            min.0,
+            min.1,
+            max.0 - min.0,
+            max.1 - min.1,
+            style_of(style)
+        ));
+    }
+
+    /// dot of `radius` pixels
+    pub fn dot(&mut self, center: (f64, f64), radius: f64, style: &str) {
+        self.add(format!(
+            r#"<circle cx="{}" cy="{}" r="{}" style="{}"/>"#,
+            center.0,
+            center.1,
+            self.pixels(radius),
+            style_of(style)
+        ));
+    }
+
+    /// text `size` pixels high, starting at `position` on its baseline
+    pub fn text(&mut self, position: (f64, f64), size: f64, text: &str) {
+        let escaped = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        self.add(format!(
+            r#"<text x="{}" y="{}" font-size="{}" font-family="monospace">{escaped}</text>"#,
+            position.0,
+            position.1,
+            self.pixels(size)
+        ));
+    }
+
+    /// top left corner of the drawing, including the margin, to put captions at
+    pub fn top_left(&self) -> (f64, f64) {
+        self.min
+    }
+
+    pub fn artifact(&self, name: &str) -> Artifact {
+        Artifact::text(name, self.to_string())
+    }
+
+    fn add(&mut self, element: String) {
+        self.elements.push(element);
+    }
+}
+
+fn style_of(style: &str) -> String {
+    format!("vector-effect:non-scaling-stroke;{style}")
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scale = SIZE / self.size.0.max(self.size.1);
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="{} {} {} {}">"#,
+            (self.size.0 * scale).max(1.0),
+            (self.size.1 * scale).max(1.0),
+            self.min.0,
+            self.min.1,
+            self.size.0,
+            self.size.1
+        )?;
+        for element in &self.elements {
+            writeln!(f, "  {element}")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_in_puzzle_coordinates() {
+        let mut svg = Svg::new((0.0, 0.0), (200.0, 100.0));
+        svg.polygon(&[(0.0, 0.0), (200.0, 0.0), (0.0, 100.0)], "fill:red");
+        svg.text((0.0, 0.0), 20.0, "a < b");
+        let drawn = svg.to_string();
+
+        assert!(drawn.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="1000" height="545" viewBox="-10 -10 220 120">"#
+        ));
+        assert!(drawn.contains(r#"<polygon points="0,0 200,0 0,100""#));
+        assert!(drawn.contains(r#"font-size="4.4""#));
+        assert!(drawn.contains("a &lt; b"));
+        assert!(drawn.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::Path;
+
+use toml::{Table, Value};
+
+use crate::{Answer, LEGACY_YEAR};
+
+/// default location of the answers file, relative to the working directory
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// known correct answers, read from a file like
+/// ```toml
+/// [2023.day01]
+/// part1 = 55477
+/// part2 = "54431"
+/// ```
+/// sections without a year (`[day01]`) belong to `LEGACY_YEAR`
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(u16, u8, u8), String>,
+}
+
+/// result of comparing a computed answer to the expected one
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl ExpectedAnswers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+        Self::parse(&content).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table: Table = content.parse().map_err(|err| format!("{err}"))?;
+        let mut answers = BTreeMap::new();
+
+        for (key, value) in table.iter() {
+            match key.parse::<u16>() {
+                Ok(year) => {
+                    let days = value
+                        .as_table()
+                        .ok_or(format!("[{year}] must be a table"))?;
+                    for (day_key, parts) in days.iter() {
+                        parse_day(year, day_key, parts, &mut answers)?;
+                    }
+                }
+                Err(_) => parse_day(LEGACY_YEAR, key, value, &mut answers)?,
+            }
+        }
+
+        Ok(ExpectedAnswers { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers
+            .get(&(year, day, part))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: u8, actual: &Answer) -> Verdict {
+        match self.get(year, day, part) {
+            None => Verdict::Missing,
+            Some(expected) if *expected == actual.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// reads a `[dayNN]` section with `part1` and/or `part2`
+fn parse_day(
+    year: u16,
+    day_key: &str,
+    parts: &Value,
+    answers: &mut BTreeMap<(u16, u8, u8), String>,
+) -> Result<(), String> {
+    let day: u8 = day_key
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .ok_or(format!(
+            "unexpected section [{day_key}], expected [dayNN] or [YYYY.dayNN]"
+        ))?;
+    let parts = parts
+        .as_table()
+        .ok_or(format!("[{day_key}] must be a table"))?;
+
+    for (part_key, value) in parts.iter() {
+        let part = match part_key.as_str() {
+            "part1" => 1,
+            "part2" => 2,
+            _ => return Err(format!("unexpected key {part_key} in [{day_key}]")),
+        };
+        let answer = match value {
+            Value::String(answer) => answer.clone(),
+            Value::Integer(answer) => answer.to_string(),
+            _ => return Err(format!("{day_key}.{part_key} must be a string or integer")),
+        };
+        answers.insert((year, day, part), answer);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_sections_with_and_without_year() {
+        let answers = ExpectedAnswers::parse(
+            "[day01]\npart1 = 1\n\n[2024.day01]\npart1 = \"2\"\npart2 = 3\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(LEGACY_YEAR, 1, 1), Some("1"));
+        assert_eq!(answers.get(2024, 1, 1), Some("2"));
+        assert_eq!(answers.get(2024, 1, 2), Some("3"));
+        assert_eq!(answers.get(2024, 2, 1), None);
+        assert!(ExpectedAnswers::parse("[2024.one]\npart1 = 1\n").is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::generate;
+use crate::{Answer, AocError, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+const DIGITS_TO_FIND: [(&str, u32); 18] = [
+    ("1", 1),
+    ("one", 1),
+    ("2", 2),
+    ("two", 2),
+    ("3", 3),
+    ("three", 3),
+    ("4", 4),
+    ("four", 4),
+    ("5", 5),
+    ("five", 5),
+    ("6", 6),
+    ("six", 6),
+    ("7", 7),
+    ("seven", 7),
+    ("8", 8),
+    ("eight", 8),
+    ("9", 9),
+    ("nine", 9),
+];
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = String;
+
+    const PART_ONE: &'static str = "The sum of the calibration values (digits only) is {}";
+    const PART_TWO: &'static str = "The actual sum including words is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.clone())
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn part_one(input: &str) -> u32 {
+    let mut sum = 0;
+
+    for line in input.lines() {
+        let mut first = true;
+        let mut first_digit = 0;
+        let mut last_digit = 0;
+
+        for char in line.chars() {
+            if char.is_digit(10) {
+                let digit = char.to_digit(10).expect("Not a digit!");
+                if first {
+                    first_digit = digit;
+                    last_digit = digit;
+                    first = false;
+                } else {
+                    last_digit = digit;
+                }
+            }
+        }
+
+        sum = sum + (10 * first_digit) + last_digit;
+    }
+
+    sum
+}
+
+fn part_two(input: &str) -> u32 {
+    let mut sum = 0;
+
+    for line in input.lines() {
+        let mut first_digit = 0;
+        let mut first_digit_idx = usize::MAX;
+        let mut last_digit = 0;
+        let mut last_digit_idx: usize = 0;
+
+        for digit in DIGITS_TO_FIND {
+            // find first digit
+            match line.find(digit.0) {
+                None => (),
+                Some(idx) => {
+                    if idx <= first_digit_idx {
+                        first_digit = digit.1;
+                        first_digit_idx = idx;
+                    }
+                }
+            }
+
+            // find last digit
+            match line.rfind(digit.0) {
+                None => (),
+                Some(idx) => {
+                    if idx >= last_digit_idx {
+                        last_digit = digit.1;
+                        last_digit_idx = idx;
+                    }
+                }
+            }
+        }
+
+        sum = sum + (10 * first_digit) + last_digit;
+    }
+
+    sum
+}
+
+/// letters with digits and spelled out digits mixed in, every line has at least one real digit
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size * 10 {
+        let pieces = rng.gen_range(1..=6);
+        let digit_piece = rng.gen_range(0..pieces);
+        for piece in 0..pieces {
+            if piece == digit_piece {
+                input.push((b'0' + rng.gen_range(1..=9)) as char);
+            } else if rng.gen_bool(0.3) {
+                input += DIGITS_TO_FIND[rng.gen_range(0..DIGITS_TO_FIND.len())].0;
+            } else {
+                let len = rng.gen_range(1..=4);
+                input += &generate::word(rng, generate::LOWERCASE, len);
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("example_input_part_two.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day01.parse(EXAMPLE).unwrap();
+        assert_eq!(Day01.part_one(&input).unwrap(), Answer::Unsigned(142));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day01.parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(Day01.part_two(&input).unwrap(), Answer::Unsigned(281));
+    }
+
+    #[test]
+    fn part_two_without_words() {
+        // without spelled out digits both parts agree
+        let input = Day01.parse(EXAMPLE).unwrap();
+        assert_eq!(Day01.part_two(&input).unwrap(), Answer::Unsigned(142));
+    }
+}
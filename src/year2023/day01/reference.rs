@@ -0,0 +1,35 @@
+use super::DIGITS_TO_FIND;
+
+/// every digit in the line from left to right, including the spelled out ones if `words` is set
+fn digits(line: &str, words: bool) -> Vec<u32> {
+    (0..line.len())
+        .filter_map(|start| {
+            DIGITS_TO_FIND
+                .iter()
+                .filter(|(text, _)| words || text.len() == 1)
+                .find(|(text, _)| line.get(start..).is_some_and(|rest| rest.starts_with(text)))
+                .map(|&(_, digit)| digit)
+        })
+        .collect()
+}
+
+fn calibration_sum(input: &str, words: bool) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let digits = digits(line, words);
+            match (digits.first(), digits.last()) {
+                (Some(first), Some(last)) => 10 * first + last,
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+pub fn part_one(input: &str) -> u32 {
+    calibration_sum(input, false)
+}
+
+pub fn part_two(input: &str) -> u32 {
+    calibration_sum(input, true)
+}
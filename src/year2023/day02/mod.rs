@@ -0,0 +1,179 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use regex::Regex;
+use regex::RegexSet;
+
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = String;
+
+    const PART_ONE: &'static str = "The sum of possible game ids is {}";
+    const PART_TWO: &'static str = "The sum of game powers is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        validate_input(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.clone())
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+/// the parts work on the raw lines with regexes, so only check the format here
+/// lines must look like "Game 1: 3 blue, 4 red; 1 red, 2 green" with games numbered 1, 2, 3, ...
+fn validate_input(input_str: &str) -> Result<(), AocError> {
+    let ctx = ParseContext::new(2, input_str);
+
+    for (line_nr, line) in input_str.lines().enumerate() {
+        let (game, draws) = ctx.split_once(line, ": ")?;
+        let game_nr = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| ctx.error(game, "expected \"Game <nr>\""))?;
+        if ctx.number::<usize>(game_nr)? != line_nr + 1 {
+            return Err(ctx.error(game_nr, format!("expected game {}", line_nr + 1)));
+        }
+
+        for draw in draws.split("; ") {
+            for cubes in draw.split(", ") {
+                let (amount, color) = ctx.split_once(cubes, " ")?;
+                ctx.number::<usize>(amount)?;
+                if !matches!(color, "red" | "green" | "blue") {
+                    return Err(ctx.error(color, "unknown color"));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn part_one(input: &str) -> usize {
+    // regexes to check for {>12} red, {>13} green, {>14} blue
+    let regex_set = RegexSet::new(&[
+        r"(1[3-9]|[2-9]\d|\d{3,}) red",
+        r"(1[4-9]|[2-9]\d|\d{3,}) green",
+        r"(1[5-9]|[2-9]\d|\d{3,}) blue",
+    ])
+    .unwrap();
+
+    let mut game_sum = 0;
+
+    for (line_nr, line) in input.lines().enumerate() {
+        if !regex_set.is_match(line) {
+            game_sum += line_nr + 1; //game nr is one more than line number
+        }
+    }
+
+    game_sum
+}
+
+pub fn part_two(input: &str) -> usize {
+    // regexes with groups to extract amounts
+    let red_regex = Regex::new(r"(\d+) red").unwrap();
+    let green_regex = Regex::new(r"(\d+) green").unwrap();
+    let blue_regex = Regex::new(r"(\d+) blue").unwrap();
+
+    let mut power_sum = 0;
+
+    for line in input.lines() {
+        // find biggest amount of red cubes
+        let mut max_red: usize = 0;
+        for (_, [num_str]) in red_regex.captures_iter(line).map(|caps| caps.extract()) {
+            let num: usize = num_str.parse().unwrap();
+            if num > max_red {
+                max_red = num;
+            }
+        }
+
+        // find biggest amount of green cubes
+        let mut max_green: usize = 0;
+        for (_, [num_str]) in green_regex.captures_iter(line).map(|caps| caps.extract()) {
+            let num: usize = num_str.parse().unwrap();
+            if num > max_green {
+                max_green = num;
+            }
+        }
+
+        // find biggest amount of blue cubes
+        let mut max_blue: usize = 0;
+        for (_, [num_str]) in blue_regex.captures_iter(line).map(|caps| caps.extract()) {
+            let num: usize = num_str.parse().unwrap();
+            if num > max_blue {
+                max_blue = num;
+            }
+        }
+
+        power_sum += max_red * max_green * max_blue;
+    }
+
+    power_sum
+}
+
+/// games with up to five draws of up to 20 cubes per color
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for game in 1..=size * 10 {
+        let draw_count = rng.gen_range(1..=5);
+        let draws: Vec<String> = (0..draw_count)
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+                let color_count = rng.gen_range(1..=3);
+                colors[..color_count]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        input += &format!("Game {game}: {}\n", draws.join("; "));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day02.parse(EXAMPLE).unwrap();
+        assert_eq!(Day02.part_one(&input).unwrap(), Answer::Unsigned(8));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day02.parse(EXAMPLE).unwrap();
+        assert_eq!(Day02.part_two(&input).unwrap(), Answer::Unsigned(2286));
+    }
+}
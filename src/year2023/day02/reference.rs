@@ -0,0 +1,37 @@
+/// id of every game with the most cubes of each color shown at once, as [red, green, blue]
+fn games(input: &str) -> Vec<(usize, [usize; 3])> {
+    input
+        .lines()
+        .map(|line| {
+            let (game, draws) = line.split_once(": ").expect("checked by the parser");
+            let id = game["Game ".len()..]
+                .parse()
+                .expect("checked by the parser");
+            let mut most = [0; 3];
+            for cubes in draws.split([';', ',']) {
+                let (amount, color) = cubes.trim().split_once(' ').expect("checked by the parser");
+                let color = ["red", "green", "blue"]
+                    .iter()
+                    .position(|name| *name == color)
+                    .expect("checked by the parser");
+                most[color] = most[color].max(amount.parse().expect("checked by the parser"));
+            }
+            (id, most)
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> usize {
+    games(input)
+        .into_iter()
+        .filter(|(_, [red, green, blue])| *red <= 12 && *green <= 13 && *blue <= 14)
+        .map(|(id, _)| id)
+        .sum()
+}
+
+pub fn part_two(input: &str) -> usize {
+    games(input)
+        .into_iter()
+        .map(|(_, [red, green, blue])| red * green * blue)
+        .sum()
+}
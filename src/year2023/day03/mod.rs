@@ -1,15 +1,48 @@
-use std::fs::read_to_string;
-
-pub fn run_day() {
-    let input =
-        to_char_vec(read_to_string("src/day03/input.txt").expect("File could not be opened"));
-    let result = part_one_two(&input);
-    println!(
-        "Day 3 Part 1: The sum of the calibration numbers is {}",
-        result.0
-    );
-    println!("Day 3 Part 1: The sum of the gear ratios is {}", result.1);
-    println!()
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::grid::Grid;
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Grid<char>;
+
+    const PART_ONE: &'static str = "The sum of the calibration numbers is {}";
+    const PART_TWO: &'static str = "The sum of the gear ratios is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let ctx = ParseContext::new(3, input);
+        Grid::parse(&ctx, input, "an engine schematic", Ok)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(input).0.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(input).1.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.to_string())
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
 }
 
 #[derive(Debug)]
@@ -21,15 +54,6 @@ struct Star {
     cal_nr_2_idx: usize, // index of second adjacent cal nr in cal nr list
 }
 
-/// Turns passed String into 2D char vec (1st dimension: lines, 2nd dimension: chars in line)
-fn to_char_vec(input: String) -> Vec<Vec<char>> {
-    let mut result = Vec::new();
-    for line in input.lines() {
-        result.push(line.chars().collect());
-    }
-    result
-}
-
 fn handle_star(stars: &mut Vec<Star>, x: usize, y: usize, cal_nr_idx: usize) {
     let mut found_star = false;
     // check if we've already found something adjacent to this star before
@@ -65,52 +89,32 @@ fn handle_star(stars: &mut Vec<Star>, x: usize, y: usize, cal_nr_idx: usize) {
 }
 
 fn check_adjacency(
-    input: &Vec<Vec<char>>,
+    input: &Grid<char>,
     x: usize,
     y: usize,
     cal_nr_idx: usize,
     stars: &mut Vec<Star>,
 ) -> bool {
-    // check coordinates from x-1 to x+1 and y-1 to y+1
-    for i in -1..2 {
-        let y_idx_signed = y as i32 + i;
-        let mut y_idx = 0;
-        if y_idx_signed > 0 {
-            y_idx = y_idx_signed as usize; // usize can't be negative, so y=0 minus one must be handled
+    let mut adjacent_symbol = false;
+    for (y_idx, x_idx) in input.neighbors8((y, x)) {
+        let c = input[(y_idx, x_idx)];
+
+        // for part two: check if star is gear and note calibration numbers
+        // cal nrs are noted as indexes in list of cal nrs since the full nr isn't necessarily known at this point
+        if c == '*' {
+            handle_star(stars, x_idx, y_idx, cal_nr_idx);
         }
-        for j in -1..2 {
-            let x_idx_signed = x as i32 + j;
-            let mut x_idx = 0;
-            if x_idx_signed > 0 {
-                x_idx = x_idx_signed as usize; // usize can't be negative, so x=0 minus one must be handled
-            }
-            match input.get(y_idx) {
-                // try to get input at coordinates, if index out of bounds do nothing
-                Some(vec) => match vec.get(x_idx) {
-                    Some(c) => {
-                        // for part two: check if star is gear and note calibration numbers
-                        // cal nrs are noted as indexes in list of cal nrs since the full nr isn't necessarily known at this point
-                        if *c == '*' {
-                            handle_star(stars, x_idx, y_idx, cal_nr_idx);
-                        }
-
-                        // for part one: check if adjacent to a non-digit symbol that is not '.'
-                        // in this case, it is a calibration nr, can return true
-                        if *c != '.' && !c.is_digit(10) {
-                            return true;
-                        }
-                    }
-                    None => {}
-                },
-                None => {}
-            }
+
+        // for part one: check if adjacent to a non-digit symbol that is not '.'
+        // in this case, it is a calibration nr, but the other neighbors may still be stars
+        if c != '.' && !c.is_ascii_digit() {
+            adjacent_symbol = true;
         }
     }
-    // if all adjacencies are checked, this particular digit does not make the nr a calibration nr, return false
-    false
+    adjacent_symbol
 }
 
-fn part_one_two(input: &Vec<Vec<char>>) -> (u32, u32) {
+fn part_one_two(input: &Grid<char>) -> (u32, u32) {
     let mut calibration_sum = 0;
 
     let mut stars: Vec<Star> = Vec::new(); // list of found stars, used to find gears for part 2
@@ -121,7 +125,7 @@ fn part_one_two(input: &Vec<Vec<char>>) -> (u32, u32) {
     let mut cal_nr_idx = 0; // index of the current cal nr in the cal nr list
 
     // check input char by char
-    for (y, line) in input.iter().enumerate() {
+    for (y, line) in input.rows().enumerate() {
         for (x, char) in line.iter().enumerate() {
             if char.is_digit(10) {
                 // put together current number: move one order of magnitude (*10) and add new digit
@@ -173,3 +177,52 @@ fn part_one_two(input: &Vec<Vec<char>>) -> (u32, u32) {
 
     (calibration_sum, ratio_sum)
 }
+
+/// numbers and symbols between the dots, '*' twice as likely as the other symbols so there are gears
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"**#+$/@=%&";
+    let width = size * 5;
+    let mut input = String::new();
+    for _ in 0..width {
+        let mut line = String::new();
+        while line.len() < width {
+            match rng.gen_range(0..10) {
+                // numbers never run into each other
+                0 | 1 => line += &format!("{}.", rng.gen_range(1..1000)),
+                2 => line.push(SYMBOLS[rng.gen_range(0..SYMBOLS.len())] as char),
+                _ => line.push('.'),
+            }
+        }
+        line.truncate(width);
+        input += &line;
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day03.parse(EXAMPLE).unwrap();
+        assert_eq!(Day03.part_one(&input).unwrap(), Answer::Unsigned(4361));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day03.parse(EXAMPLE).unwrap();
+        assert_eq!(Day03.part_two(&input).unwrap(), Answer::Unsigned(467835));
+    }
+
+    #[test]
+    fn gear_after_another_symbol() {
+        // the 2 sees the # before the *, the * is still a gear
+        let input = Day03.parse("#*.\n2.3\n").unwrap();
+        assert_eq!(Day03.part_one(&input).unwrap(), Answer::Unsigned(5));
+        assert_eq!(Day03.part_two(&input).unwrap(), Answer::Unsigned(6));
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use crate::util::grid::Grid;
+
+/// every number on the schematic with the positions of its digits
+fn numbers(schematic: &Grid<char>) -> Vec<(u32, Vec<(usize, usize)>)> {
+    let mut numbers = vec![];
+    for (row, line) in schematic.rows().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            let start = col;
+            while col < line.len() && line[col].is_ascii_digit() {
+                col += 1;
+            }
+            if col == start {
+                col += 1;
+                continue;
+            }
+            let digits: String = line[start..col].iter().collect();
+            let value = digits.parse().expect("numbers on the schematic are small");
+            numbers.push((value, (start..col).map(|col| (row, col)).collect()));
+        }
+    }
+    numbers
+}
+
+/// positions next to any digit of a number, diagonally too
+fn surroundings(schematic: &Grid<char>, digits: &[(usize, usize)]) -> HashSet<(usize, usize)> {
+    digits
+        .iter()
+        .flat_map(|&digit| schematic.neighbors8(digit))
+        .collect()
+}
+
+pub fn part_one(schematic: &Grid<char>) -> u32 {
+    numbers(schematic)
+        .into_iter()
+        .filter(|(_, digits)| {
+            surroundings(schematic, digits).into_iter().any(|position| {
+                let tile = schematic[position];
+                tile != '.' && !tile.is_ascii_digit()
+            })
+        })
+        .map(|(value, _)| value)
+        .sum()
+}
+
+pub fn part_two(schematic: &Grid<char>) -> u32 {
+    let numbers: Vec<(u32, HashSet<(usize, usize)>)> = numbers(schematic)
+        .into_iter()
+        .map(|(value, digits)| (value, surroundings(schematic, &digits)))
+        .collect();
+
+    schematic
+        .iter()
+        .filter(|(_, &tile)| tile == '*')
+        .filter_map(|(star, _)| {
+            let adjacent: Vec<u32> = numbers
+                .iter()
+                .filter(|(_, surroundings)| surroundings.contains(&star))
+                .map(|(value, _)| *value)
+                .collect();
+            (adjacent.len() == 2).then(|| adjacent[0] * adjacent[1])
+        })
+        .sum()
+}
@@ -0,0 +1,194 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::util::parse;
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Card {
+    winning_nrs: Vec<u32>,
+    scratched_nrs: Vec<u32>,
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Vec<Card>;
+
+    const PART_ONE: &'static str = "The sum of the scratchcard points is {}";
+    const PART_TWO: &'static str = "The total number of scratch cards is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<Card>, AocError> {
+    let ctx = ParseContext::new(4, input_str);
+
+    input_str
+        .lines()
+        .map(|line| {
+            let (_, card_nrs) = ctx.split_once(line, ":")?; // split off ticket number
+            let (winning_nrs, scratched_nrs) = ctx.split_once(card_nrs, "|")?; // split winning numbers and numbers we have
+            Ok(Card {
+                winning_nrs: parse::numbers(&ctx, winning_nrs)?,
+                scratched_nrs: parse::numbers(&ctx, scratched_nrs)?,
+            })
+        })
+        .collect()
+}
+
+fn part_one(cards: &Vec<Card>) -> u32 {
+    let mut point_sum = 0; // counts total points
+
+    for card in cards {
+        let mut ticket_points = 0; // counts points for this ticket only
+
+        // check each number we have
+        for scratched_nr in card.scratched_nrs.iter() {
+            if card.winning_nrs.contains(scratched_nr) {
+                if ticket_points == 0 {
+                    ticket_points = 1; // if we had 0 numbers, we get 1 point now
+                } else {
+                    ticket_points *= 2; // else double the score
+                }
+            }
+        }
+        point_sum += ticket_points; // add ticket sum to total sum
+    }
+
+    point_sum
+}
+
+fn part_two(cards: &[Card]) -> Result<usize, AocError> {
+    let mut ticket_sum = 0; // total number of tickets overall
+    let mut tickets: Vec<usize> = vec![1; cards.len()]; // counts amount of each ticket
+
+    for (current_ticket_nr, card) in cards.iter().enumerate() {
+        // count matches
+        let ticket_matches = card
+            .scratched_nrs
+            .iter()
+            .filter(|nr| card.winning_nrs.contains(nr))
+            .count();
+
+        if current_ticket_nr + ticket_matches >= cards.len() {
+            return Err(AocError::solve(
+                4,
+                format!(
+                    "card {} wins copies of cards past the end of the table",
+                    current_ticket_nr + 1
+                ),
+            ));
+        }
+
+        // Each match n gets us copies of ticket current+n
+        for i in 1..(ticket_matches + 1) {
+            // number of copies gained per ticket is equal to number of copies we have of the current ticket
+            tickets[current_ticket_nr + i] += tickets[current_ticket_nr];
+        }
+        ticket_sum += tickets[current_ticket_nr]; // done with this ticket now, can add to sum
+    }
+
+    Ok(ticket_sum)
+}
+
+/// cards numbered from 1, the numbers aren't kept
+fn serialize_input(cards: &[Card]) -> String {
+    let join = |nrs: &[u32]| {
+        nrs.iter()
+            .map(|nr| nr.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    cards
+        .iter()
+        .enumerate()
+        .map(|(idx, card)| {
+            format!(
+                "Card {}: {} | {}\n",
+                idx + 1,
+                join(&card.winning_nrs),
+                join(&card.scratched_nrs)
+            )
+        })
+        .collect()
+}
+
+/// cards with 5 winning and 8 scratched numbers, none of them wins copies of cards past the end
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let card_count = size * 3;
+    let join = |nrs: &[u32]| {
+        nrs.iter()
+            .map(|nr| format!("{nr:>2}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let mut input = String::new();
+    for card in 0..card_count {
+        let mut nrs: Vec<u32> = (1..100).collect();
+        nrs.shuffle(rng);
+        let matches = rng.gen_range(0..=(card_count - card - 1).min(5));
+        let mut scratched: Vec<u32> = nrs[..matches]
+            .iter()
+            .chain(&nrs[5..13 - matches])
+            .copied()
+            .collect();
+        scratched.shuffle(rng);
+        input += &format!(
+            "Card {:>3}: {} | {}\n",
+            card + 1,
+            join(&nrs[..5]),
+            join(&scratched)
+        );
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day04.parse(EXAMPLE).unwrap();
+        assert_eq!(Day04.part_one(&input).unwrap(), Answer::Unsigned(13));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day04.parse(EXAMPLE).unwrap();
+        assert_eq!(Day04.part_two(&input).unwrap(), Answer::Unsigned(30));
+    }
+}
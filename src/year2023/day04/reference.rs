@@ -0,0 +1,29 @@
+use super::Card;
+
+fn matches(card: &Card) -> usize {
+    card.scratched_nrs
+        .iter()
+        .filter(|nr| card.winning_nrs.contains(nr))
+        .count()
+}
+
+pub fn part_one(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|card| match matches(card) {
+            0 => 0,
+            matches => 2_u32.pow(matches as u32 - 1),
+        })
+        .sum()
+}
+
+/// the card at `idx` and the copies it wins, and the copies those win and so on, one by one
+fn cards_won(cards: &[Card], idx: usize) -> usize {
+    1 + (idx + 1..=idx + matches(&cards[idx]))
+        .map(|won| cards_won(cards, won))
+        .sum::<usize>()
+}
+
+pub fn part_two(cards: &[Card]) -> usize {
+    (0..cards.len()).map(|idx| cards_won(cards, idx)).sum()
+}
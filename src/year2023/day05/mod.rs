@@ -0,0 +1,334 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::util::parse;
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = Almanac;
+
+    const PART_ONE: &'static str = "The lowest location nr is {}";
+    const PART_TWO: &'static str = "The actual lowest location nr is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<MapRange>>, // conversion blocks in order, each a list of ranges
+}
+
+struct MapRange {
+    dest_start: u64,
+    source_start: u64,
+    length: u64,
+}
+
+struct Seed {
+    number: u64,
+    converted: bool,
+}
+
+#[derive(Debug)]
+struct SeedRange {
+    start: u64,
+    end: u64, // exclusive
+    converted: bool,
+}
+
+fn parse_input(input_str: &str) -> Result<Almanac, AocError> {
+    let ctx = ParseContext::new(5, input_str);
+    let input_str = input_str.trim_end();
+    let (seed_str, maps) = ctx.split_once(input_str, "\n\n")?; // split once by empty line
+
+    // extract seeds
+    let (_, seed_nrs) = ctx.split_once(seed_str, ": ")?;
+    let seeds = parse::numbers(&ctx, seed_nrs)?;
+
+    // split into conversion blocks by empty lines
+    let mut blocks = vec![];
+    for block in maps.split("\n\n") {
+        let (_, ranges) = ctx.split_once(block, "\n")?; // toss header
+        let mut map = vec![];
+        for range in ranges.lines() {
+            let nrs: Vec<&str> = range.split_whitespace().collect();
+            if nrs.len() != 3 {
+                return Err(ctx.error(range, "expected destination start, source start and length"));
+            }
+            let map_range = MapRange {
+                dest_start: ctx.number(nrs[0])?,
+                source_start: ctx.number(nrs[1])?,
+                length: ctx.number(nrs[2])?,
+            };
+            if map_range.dest_start.checked_add(map_range.length).is_none()
+                || map_range
+                    .source_start
+                    .checked_add(map_range.length)
+                    .is_none()
+            {
+                return Err(ctx.error(range, "range is too large"));
+            }
+            map.push(map_range);
+        }
+        blocks.push(map);
+    }
+
+    Ok(Almanac {
+        seeds,
+        maps: blocks,
+    })
+}
+
+fn part_one(almanac: &Almanac) -> u64 {
+    let mut seeds: Vec<Seed> = almanac
+        .seeds
+        .iter()
+        .map(|&number| Seed {
+            number,
+            converted: false,
+        })
+        .collect();
+
+    for block in almanac.maps.iter() {
+        for range in block {
+            for seed in &mut seeds {
+                if seed.converted || seed.number < range.source_start {
+                    continue;
+                }
+
+                let from_range_start = seed.number - range.source_start;
+                if from_range_start < range.length {
+                    seed.number = range.dest_start + from_range_start;
+                    seed.converted = true;
+                }
+            }
+        }
+
+        // reset conversion markers
+        for seed in &mut seeds {
+            seed.converted = false;
+        }
+    }
+
+    let min_num = seeds
+        .iter()
+        .fold(u64::MAX, |min_val, val| val.number.min(min_val));
+    min_num
+}
+
+fn part_two(almanac: &Almanac) -> Result<u64, AocError> {
+    // seeds come in pairs of start and length
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(AocError::solve(5, "seed ranges need a start and a length"));
+    }
+    let mut seed_ranges = vec![];
+    for pair in almanac.seeds.chunks(2) {
+        let end = pair[0].checked_add(pair[1]).ok_or_else(|| {
+            AocError::solve(
+                5,
+                format!("seed range {} {} is too large", pair[0], pair[1]),
+            )
+        })?;
+        seed_ranges.push(SeedRange {
+            start: pair[0],
+            end,
+            converted: false,
+        });
+    }
+
+    for block in almanac.maps.iter() {
+        for range in block {
+            let mut new_seed_ranges = vec![];
+            let dest_start = range.dest_start;
+            let conversion_range_start = range.source_start;
+            let conversion_range_end: u64 = conversion_range_start + range.length; // exclusive
+
+            for seed_range in &mut seed_ranges {
+                if seed_range.converted {
+                    let new_range = SeedRange {
+                        start: seed_range.start,
+                        end: seed_range.end,
+                        converted: seed_range.converted,
+                    };
+                    new_seed_ranges.push(new_range);
+                    continue;
+                }
+
+                // if seed range is entirely before conversion range
+                if seed_range.end < conversion_range_start {
+                    let new_range = SeedRange {
+                        start: seed_range.start,
+                        end: seed_range.end,
+                        converted: seed_range.converted,
+                    };
+                    new_seed_ranges.push(new_range);
+                    continue;
+                }
+
+                // if seed range is entirely after conversion range
+                if seed_range.start > conversion_range_end {
+                    let new_range = SeedRange {
+                        start: seed_range.start,
+                        end: seed_range.end,
+                        converted: seed_range.converted,
+                    };
+                    new_seed_ranges.push(new_range);
+                    continue;
+                }
+
+                // now they must overlap
+
+                // possible new range: source before conversion range (not converted)
+                if seed_range.start < conversion_range_start {
+                    let new_before_range = SeedRange {
+                        start: seed_range.start,
+                        end: conversion_range_start.min(seed_range.end),
+                        converted: false,
+                    };
+                    new_seed_ranges.push(new_before_range);
+                }
+
+                // possible conversion: source within conversion range (adjust start & end accordingly)
+                if seed_range.end > conversion_range_start
+                    && seed_range.start < conversion_range_end
+                {
+                    let start_converting = seed_range.start.max(conversion_range_start);
+                    let stop_converting = seed_range.end.min(conversion_range_end);
+                    let new_start = dest_start + (start_converting - conversion_range_start);
+                    let new_end = dest_start + (stop_converting - conversion_range_start);
+                    let new_converted_range = SeedRange {
+                        start: new_start,
+                        end: new_end,
+                        converted: true,
+                    };
+                    new_seed_ranges.push(new_converted_range);
+                }
+
+                // possible new range: source after conversion range
+                if seed_range.end > conversion_range_end {
+                    let new_after_range = SeedRange {
+                        start: conversion_range_end.max(seed_range.start),
+                        end: seed_range.end,
+                        converted: false,
+                    };
+                    new_seed_ranges.push(new_after_range);
+                }
+            }
+            seed_ranges = new_seed_ranges;
+        }
+
+        // reset conversion markers
+        for seed_range in &mut seed_ranges {
+            seed_range.converted = false;
+        }
+    }
+
+    let min_num: u64 = seed_ranges
+        .iter()
+        .fold(u64::MAX, |min_val, val| val.start.min(min_val));
+    Ok(min_num)
+}
+
+/// the block headers are thrown away when parsing, so they are just numbered
+fn serialize_input(almanac: &Almanac) -> String {
+    let seeds: Vec<String> = almanac.seeds.iter().map(|seed| seed.to_string()).collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for (idx, block) in almanac.maps.iter().enumerate() {
+        input += &format!("\nblock-{} map:\n", idx + 1);
+        for range in block {
+            input += &format!(
+                "{} {} {}\n",
+                range.dest_start, range.source_start, range.length
+            );
+        }
+    }
+    input
+}
+
+/// seed ranges and conversion blocks with ranges that don't overlap, all numbers below 100 * size
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    const BLOCKS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    let limit = 100 * size as u64;
+
+    let seeds: Vec<String> = (0..size)
+        .flat_map(|_| [rng.gen_range(0..limit), rng.gen_range(1..=limit / 4)])
+        .map(|nr| nr.to_string())
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for block in BLOCKS {
+        input += &format!("\n{block} map:\n");
+        let mut ranges = vec![];
+        let mut source_start = rng.gen_range(0..100);
+        while source_start < limit {
+            let length = rng.gen_range(1..=100);
+            ranges.push(format!(
+                "{} {source_start} {length}\n",
+                rng.gen_range(0..limit)
+            ));
+            source_start += length + rng.gen_range(0..100);
+        }
+        ranges.shuffle(rng);
+        input += &ranges.concat();
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day05.parse(EXAMPLE).unwrap();
+        assert_eq!(Day05.part_one(&input).unwrap(), Answer::Unsigned(35));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day05.parse(EXAMPLE).unwrap();
+        assert_eq!(Day05.part_two(&input).unwrap(), Answer::Unsigned(46));
+    }
+}
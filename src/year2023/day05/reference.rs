@@ -0,0 +1,35 @@
+use super::Almanac;
+
+/// number after going through every conversion block, a number no range covers stays the same
+fn location(almanac: &Almanac, seed: u64) -> u64 {
+    almanac.maps.iter().fold(seed, |number, block| {
+        block
+            .iter()
+            .find(|range| {
+                range.source_start <= number && number - range.source_start < range.length
+            })
+            .map_or(number, |range| {
+                range.dest_start + (number - range.source_start)
+            })
+    })
+}
+
+pub fn part_one(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| location(almanac, seed))
+        .min()
+        .unwrap_or(u64::MAX)
+}
+
+/// converts every single seed of every range
+pub fn part_two(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .chunks(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(|seed| location(almanac, seed))
+        .min()
+        .unwrap_or(u64::MAX)
+}
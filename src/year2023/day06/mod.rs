@@ -0,0 +1,159 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = (Vec<Race>, Race);
+
+    const PART_ONE: &'static str = "Product of options to win each race is {}";
+    const PART_TWO: &'static str = "There are {} ways to win the long race";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(&input.0).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(&input.1).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(&input.0))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(&input.0).into(),
+            _ => reference::part_two(&input.0).into(),
+        }))
+    }
+}
+
+/// returns the individual races and the long race (all digits of a line put together, ignoring spaces)
+fn parse_input(input_str: &str) -> Result<(Vec<Race>, Race), AocError> {
+    let ctx = ParseContext::new(6, input_str);
+    let (time_str, distance_str) = ctx.split_once(input_str, "\n")?;
+    let (_, times) = ctx.split_once(time_str, ":")?;
+    let (_, distances) = ctx.split_once(distance_str.trim_end(), ":")?;
+
+    if times.split_whitespace().count() != distances.split_whitespace().count() {
+        return Err(ctx.error(distances, "expected as many distances as times"));
+    }
+
+    let races = times
+        .split_whitespace()
+        .zip(distances.split_whitespace())
+        .map(|(time, distance)| {
+            Ok(Race {
+                time: ctx.number(time)?,
+                distance: ctx.number(distance)?,
+            })
+        })
+        .collect::<Result<_, AocError>>()?;
+
+    let long_race = Race {
+        time: ctx.number(&times.replace(" ", ""))?,
+        distance: ctx.number(&distances.replace(" ", ""))?,
+    };
+
+    Ok((races, long_race))
+}
+
+fn part_one(races: &Vec<Race>) -> u64 {
+    let mut winning_option_product = 1;
+
+    for race in races {
+        let mut winning_options = 0;
+        for speed in 1..race.time {
+            let running_time = race.time - speed;
+            let distance_traveled = speed * running_time;
+            if distance_traveled > race.distance {
+                winning_options += 1;
+            }
+        }
+        winning_option_product *= winning_options;
+    }
+
+    winning_option_product
+}
+
+fn part_two(long_race: &Race) -> u64 {
+    let mut winning_options = 0;
+
+    for speed in 1..long_race.time {
+        let running_time = long_race.time - speed;
+        let distance_traveled = speed * running_time;
+        if distance_traveled > long_race.distance {
+            winning_options += 1;
+        }
+    }
+    winning_options
+}
+
+/// the long race is read from the same lines as the separate races
+fn serialize_input(races: &[Race]) -> String {
+    let times: Vec<String> = races.iter().map(|race| race.time.to_string()).collect();
+    let distances: Vec<String> = races.iter().map(|race| race.distance.to_string()).collect();
+    format!(
+        "Time: {}\nDistance: {}\n",
+        times.join(" "),
+        distances.join(" ")
+    )
+}
+
+/// up to three races that can all be won, times stay below 100 so part 2 doesn't try millions of speeds
+fn generate_input(rng: &mut StdRng, _size: usize) -> String {
+    let race_count = rng.gen_range(1..=3);
+    let races: Vec<(u64, u64)> = (0..race_count)
+        .map(|_| {
+            let time = rng.gen_range(7..100);
+            let best_distance = (time / 2) * (time - time / 2);
+            (time, rng.gen_range(0..best_distance))
+        })
+        .collect();
+
+    let times: String = races.iter().map(|(time, _)| format!("{time:>6}")).collect();
+    let distances: String = races
+        .iter()
+        .map(|(_, distance)| format!("{distance:>6}"))
+        .collect();
+    format!("Time:    {times}\nDistance:{distances}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day06.parse(EXAMPLE).unwrap();
+        assert_eq!(Day06.part_one(&input).unwrap(), Answer::Unsigned(288));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day06.parse(EXAMPLE).unwrap();
+        assert_eq!(Day06.part_two(&input).unwrap(), Answer::Unsigned(71503));
+    }
+}
@@ -0,0 +1,24 @@
+use super::Race;
+
+/// how long the button can be held to go further than `distance`, trying every time
+fn ways_to_win(time: u64, distance: u64) -> usize {
+    (0..=time)
+        .filter(|hold| hold * (time - hold) > distance)
+        .count()
+}
+
+pub fn part_one(races: &[Race]) -> usize {
+    races
+        .iter()
+        .map(|race| ways_to_win(race.time, race.distance))
+        .product()
+}
+
+/// the long race is read from the separate races written next to each other, not from the input
+pub fn part_two(races: &[Race]) -> usize {
+    let long = |number: fn(&Race) -> u64| -> u64 {
+        let digits: String = races.iter().map(|race| number(race).to_string()).collect();
+        digits.parse().expect("the long race fits")
+    };
+    ways_to_win(long(|race| race.time), long(|race| race.distance))
+}
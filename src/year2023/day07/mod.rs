@@ -1,6 +1,15 @@
 use std::collections::HashMap;
 
-struct Round {
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Round {
     bet: u32,
     score: u32,
     joker_score: u32,
@@ -16,17 +25,44 @@ enum HandType {
     Nothing = 0,
 }
 
-pub fn run_day() {
-    let mut rounds = parse_input();
-    println!("Day 7 Part 1: Total winnings are {}", part_one(&mut rounds));
-    println!(
-        "Day 7 Part 1: Winnings with joker rule are {}",
-        part_two(&mut rounds)
-    );
-    println!();
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = Vec<Round>;
+
+    const PART_ONE: &'static str = "Total winnings are {}";
+    const PART_TWO: &'static str = "Winnings with joker rule are {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(&mut input.iter().collect()).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(&mut input.iter().collect()).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
 }
 
-fn part_one(rounds: &mut Vec<Round>) -> u32 {
+fn part_one(rounds: &mut Vec<&Round>) -> u32 {
     let mut winnings = 0;
 
     // sort descending by score to get vec where index is rank minus one
@@ -40,7 +76,7 @@ fn part_one(rounds: &mut Vec<Round>) -> u32 {
     winnings
 }
 
-fn part_two(rounds: &mut Vec<Round>) -> u32 {
+fn part_two(rounds: &mut Vec<&Round>) -> u32 {
     let mut winnings = 0;
 
     // sort descending by score (with joker rule) to get vec where index is rank minus one
@@ -54,13 +90,16 @@ fn part_two(rounds: &mut Vec<Round>) -> u32 {
     winnings
 }
 
-fn parse_input() -> Vec<Round> {
-    let input = include_str!("input.txt");
+fn parse_input(input: &str) -> Result<Vec<Round>, AocError> {
+    let ctx = ParseContext::new(7, input);
     let mut rounds = Vec::new();
 
     for line in input.lines() {
-        let (hand, bet_str) = line.split_once(" ").unwrap();
-        let bet: u32 = bet_str.parse().unwrap();
+        let (hand, bet_str) = ctx.split_once(line, " ")?;
+        if hand.chars().count() != 5 || hand.chars().any(|card| get_card_score(card, false) == 0) {
+            return Err(ctx.error(hand, "expected a hand of five cards"));
+        }
+        let bet: u32 = ctx.number(bet_str)?;
         let round = Round {
             bet: bet,
             score: compute_hand_score(hand, false),
@@ -69,7 +108,7 @@ fn parse_input() -> Vec<Round> {
         rounds.push(round);
     }
 
-    rounds
+    Ok(rounds)
 }
 
 /// Score is:
@@ -78,7 +117,7 @@ fn parse_input() -> Vec<Round> {
 /// If joker_rule is true, jokers are worth 1 individually (rather than 11) but counted as most beneficial other card for hand type
 fn compute_hand_score(hand_str: &str, joker_rule: bool) -> u32 {
     let mut score = 0;
-    let hand: Vec<u32> = hand_str   // vec of cards in hand as scores (rather than chars)
+    let hand: Vec<u32> = hand_str // vec of cards in hand as scores (rather than chars)
         .chars()
         .map(|card| get_card_score(card, joker_rule))
         .collect();
@@ -175,3 +214,56 @@ fn get_hand_type(counts: Vec<u32>) -> HandType {
 
     hand_type
 }
+
+fn serialize_input(rounds: &[Round]) -> String {
+    rounds
+        .iter()
+        .map(|round| format!("{} {}\n", hand(round), round.bet))
+        .collect()
+}
+
+/// the cards of a hand are the base 15 digits of its score, below the hand type
+fn hand(round: &Round) -> String {
+    (0..5)
+        .rev()
+        .map(|position| {
+            let card_score = round.score / 15_u32.pow(position) % 15;
+            "23456789TJQKA"
+                .chars()
+                .find(|&card| get_card_score(card, false) == card_score)
+                .unwrap_or('?')
+        })
+        .collect()
+}
+
+/// hands made of only a few kinds of cards each, so there are pairs, full houses and so on
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size * 10 {
+        let mut cards: Vec<char> = "23456789TJQKA".chars().collect();
+        cards.shuffle(rng);
+        let kinds = rng.gen_range(1..=5);
+        let hand: String = (0..5).map(|_| cards[rng.gen_range(0..kinds)]).collect();
+        input += &format!("{hand} {}\n", rng.gen_range(1..=1000));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day07.parse(EXAMPLE).unwrap();
+        assert_eq!(Day07.part_one(&input).unwrap(), Answer::Unsigned(6440));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day07.parse(EXAMPLE).unwrap();
+        assert_eq!(Day07.part_two(&input).unwrap(), Answer::Unsigned(5905));
+    }
+}
@@ -0,0 +1,71 @@
+use super::{hand, Round};
+
+const CARDS: &str = "23456789TJQKA";
+/// jokers are the weakest card on their own
+const JOKER_CARDS: &str = "J23456789TQKA";
+
+/// 6 for five of a kind, 5 for four of a kind and so on down to 0 for a high card
+fn hand_type(hand: &[char]) -> u8 {
+    let mut counts: Vec<usize> = CARDS
+        .chars()
+        .map(|card| hand.iter().filter(|&&other| other == card).count())
+        .filter(|&count| count > 0)
+        .collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    match counts[..] {
+        [5] => 6,
+        [4, 1] => 5,
+        [3, 2] => 4,
+        [3, 1, 1] => 3,
+        [2, 2, 1] => 2,
+        [2, 1, 1, 1] => 1,
+        _ => 0,
+    }
+}
+
+/// best type of every hand the jokers can stand in for
+fn joker_hand_type(hand: &[char]) -> u8 {
+    match hand.iter().position(|&card| card == 'J') {
+        None => hand_type(hand),
+        Some(joker) => CARDS
+            .chars()
+            .filter(|&card| card != 'J')
+            .map(|card| {
+                let mut hand = hand.to_vec();
+                hand[joker] = card;
+                joker_hand_type(&hand)
+            })
+            .max()
+            .unwrap_or_default(),
+    }
+}
+
+/// ranks the hands by the type and then the cards from `cards`, weakest first
+fn winnings(rounds: &[Round], hand_type: fn(&[char]) -> u8, cards: &str) -> u32 {
+    let mut hands: Vec<((u8, Vec<usize>), u32)> = rounds
+        .iter()
+        .map(|round| {
+            let hand: Vec<char> = hand(round).chars().collect();
+            let card_values = hand
+                .iter()
+                .map(|&card| cards.find(card).unwrap_or_default());
+            ((hand_type(&hand), card_values.collect()), round.bet)
+        })
+        .collect();
+    // equal hands keep their order, like in the real solution
+    hands.sort_by(|a, b| a.0.cmp(&b.0));
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, bet))| (idx as u32 + 1) * bet)
+        .sum()
+}
+
+pub fn part_one(rounds: &[Round]) -> u32 {
+    winnings(rounds, hand_type, CARDS)
+}
+
+pub fn part_two(rounds: &[Round]) -> u32 {
+    winnings(rounds, joker_hand_type, JOKER_CARDS)
+}
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate;
+use crate::util::cycle::{self, Cycle, Periodic};
+use crate::util::dot::Dot;
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Directions {
+    left: String,
+    right: String,
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = (String, HashMap<String, Directions>);
+
+    const PART_ONE: &'static str = "You need to take {} steps.";
+    const PART_TWO: &'static str = "Ghosts need to take {} steps.";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok((parse_directions(input)?.to_string(), parse_map(input)?))
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(&input.0, &input.1)?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(&input.0, &input.1)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(&input.0, &input.1))
+    }
+
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(vec![node_map(&input.1).artifact("nodes.dot")])
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(&input.0, &input.1).into(),
+            _ => reference::part_two(&input.0, &input.1).into(),
+        }))
+    }
+}
+
+fn part_one(directions: &str, map: &HashMap<String, Directions>) -> Result<u32, AocError> {
+    let mut steps = 0;
+    let mut current_node = "AAA";
+
+    if !map.contains_key(current_node) {
+        return Err(AocError::solve(8, "there is no node AAA to start from"));
+    }
+
+    while !current_node.eq("ZZZ") {
+        // after visiting every node at every position of the directions we must be going in circles
+        if steps as usize > map.len() * directions.len() {
+            return Err(AocError::solve(8, "ZZZ can't be reached from AAA"));
+        }
+
+        for direction in directions.chars() {
+            if direction == 'L' {
+                current_node = &map[current_node].left;
+            } else {
+                current_node = &map[current_node].right;
+            }
+
+            steps += 1;
+
+            if current_node.eq("ZZZ") {
+                break;
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+fn part_two(directions: &str, map: &HashMap<String, Directions>) -> Result<u64, AocError> {
+    let starting_nodes: Vec<&str> = map
+        .keys()
+        .filter(|node| node.ends_with("A"))
+        .map(|node| node.as_str())
+        .collect();
+    let directions = directions.as_bytes();
+
+    // a ghost is at some node and some position in the directions, so it has to start going in circles eventually
+    let walks: Vec<_> = starting_nodes
+        .iter()
+        .map(|&starting_node| {
+            cycle::hashing((starting_node, 0), |&(node, idx)| {
+                let next = match directions[idx] {
+                    b'L' => &map[node].left,
+                    _ => &map[node].right,
+                };
+                (next.as_str(), (idx + 1) % directions.len())
+            })
+        })
+        .collect();
+    let at_end = |walk: &(Cycle, Vec<(&str, usize)>), step: usize| {
+        walk.1[walk.0.reduce(step)].0.ends_with("Z")
+    };
+
+    // before every ghost is in its loop, simply check every step
+    let longest_tail = walks.iter().map(|(walk, _)| walk.tail).max().unwrap_or(0);
+    if let Some(step) = (1..longest_tail).find(|&step| walks.iter().all(|walk| at_end(walk, step)))
+    {
+        return Ok(step as u64);
+    }
+
+    // within its loop a ghost reaches end nodes periodically, maybe at several steps of the loop
+    // combine every choice of those, the ghosts usually have just one
+    let mut candidates = vec![Periodic {
+        offset: 0,
+        period: 1,
+    }];
+    for (walk, starting_node) in walks.iter().zip(starting_nodes) {
+        let ends: Vec<Periodic> = (walk.0.tail..walk.0.tail + walk.0.period)
+            .filter(|&step| at_end(walk, step))
+            .map(|step| Periodic {
+                offset: step as u64,
+                period: walk.0.period as u64,
+            })
+            .collect();
+        if ends.is_empty() {
+            return Err(AocError::solve(
+                8,
+                format!("no end node can be reached from {starting_node}"),
+            ));
+        }
+
+        candidates = candidates
+            .iter()
+            .flat_map(|&candidate| {
+                ends.iter()
+                    .filter_map(move |&end| cycle::combine(&[candidate, end]))
+            })
+            .collect();
+    }
+
+    candidates
+        .iter()
+        .map(|candidate| candidate.offset)
+        .min()
+        .ok_or_else(|| AocError::solve(8, "the ghosts are never all at end nodes at the same time"))
+}
+
+fn parse_directions(input: &str) -> Result<&str, AocError> {
+    let ctx = ParseContext::new(8, input);
+    let (directions, _) = ctx.split_once(input, "\n")?;
+    if directions.is_empty()
+        || directions
+            .chars()
+            .any(|direction| direction != 'L' && direction != 'R')
+    {
+        return Err(ctx.error(directions, "expected a line of L and R"));
+    }
+    Ok(directions)
+}
+
+fn parse_map(input: &str) -> Result<HashMap<String, Directions>, AocError> {
+    let ctx = ParseContext::new(8, input);
+    let mut map = HashMap::new();
+    let mut references = vec![];
+
+    // split off directions at empty line
+    let (_, map_str) = ctx.split_once(input, "\n\n")?;
+
+    // extract directions into hashmap (node to Direction with left/right)
+    for line in map_str.lines() {
+        let (source_node, dir_str) = ctx.split_once(line, " = (")?;
+        let (left, rest) = ctx.split_once(dir_str, ", ")?;
+        let (right, _) = ctx.split_once(rest, ")")?;
+        references.push(left);
+        references.push(right);
+        let directions = Directions {
+            left: left.to_string(),
+            right: right.to_string(),
+        };
+        map.insert(source_node.to_string(), directions);
+    }
+
+    // every node we can walk to must exist
+    if let Some(node) = references.into_iter().find(|node| !map.contains_key(*node)) {
+        return Err(ctx.error(node, "unknown node"));
+    }
+
+    Ok(map)
+}
+
+fn serialize_input(directions: &str, map: &HashMap<String, Directions>) -> String {
+    let mut nodes: Vec<(&String, &Directions)> = map.iter().collect();
+    nodes.sort_by_key(|(node, _)| *node);
+    let lines: String = nodes
+        .iter()
+        .map(|(node, directions)| format!("{node} = ({}, {})\n", directions.left, directions.right))
+        .collect();
+    format!("{directions}\n\n{lines}")
+}
+
+/// the nodes with arrows to their left and right neighbors, the start nodes green and the end nodes red
+fn node_map(map: &HashMap<String, Directions>) -> Dot {
+    let mut nodes: Vec<(&String, &Directions)> = map.iter().collect();
+    nodes.sort_by_key(|(node, _)| *node);
+
+    let mut dot = Dot::directed();
+    for (node, directions) in nodes {
+        if node.ends_with('A') {
+            dot.node(node, &[("style", "filled"), ("fillcolor", "palegreen")]);
+        } else if node.ends_with('Z') {
+            dot.node(node, &[("style", "filled"), ("fillcolor", "lightcoral")]);
+        }
+
+        if directions.left == directions.right {
+            dot.edge(node, &directions.left, &[("label", "L, R")]);
+        } else {
+            dot.edge(node, &directions.left, &[("label", "L")]);
+            dot.edge(node, &directions.right, &[("label", "R")]);
+        }
+    }
+    dot
+}
+
+/// every ghost walks around its own circle of nodes, from its start to its end node and back
+/// like in the real inputs the circles are a multiple of the directions long, so each ghost is at its end periodically
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    // nodes in the middle of a circle must not end in A or Z
+    const MIDDLE: &str = "BCDEFGHIJKLMNOPQRSTUVWXY";
+    let direction_count = rng.gen_range(1..=size);
+    let directions: String = (0..direction_count)
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let ghosts = rng.gen_range(1..=size.min(5));
+    let lengths: Vec<usize> = (0..ghosts)
+        .map(|_| direction_count * rng.gen_range(2..=size + 1))
+        .collect();
+    let prefixes = generate::distinct_words(rng, MIDDLE, 2, ghosts - 1, &[]);
+    let middle_count = lengths.iter().map(|length| length - 2).sum();
+    let mut middle = generate::distinct_words(rng, MIDDLE, 3, middle_count, &[]).into_iter();
+
+    let mut lines = vec![];
+    for (ghost, length) in lengths.into_iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (
+                prefixes[ghost - 1].clone() + "A",
+                prefixes[ghost - 1].clone() + "Z",
+            ),
+        };
+        let mut circle = vec![start];
+        circle.extend(middle.by_ref().take(length - 2));
+        circle.push(end);
+        for (idx, node) in circle.iter().enumerate() {
+            let next = &circle[(idx + 1) % length];
+            lines.push(format!("{node} = ({next}, {next})\n"));
+        }
+    }
+    lines.shuffle(rng);
+
+    format!("{directions}\n\n{}", lines.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+    const EXAMPLE_2: &str = include_str!("example_input_2.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("example_input_part_two.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day08.parse(EXAMPLE).unwrap();
+        assert_eq!(Day08.part_one(&input).unwrap(), Answer::Unsigned(2));
+    }
+
+    #[test]
+    fn part_one_repeating_directions() {
+        let input = Day08.parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day08.part_one(&input).unwrap(), Answer::Unsigned(6));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day08.parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(Day08.part_two(&input).unwrap(), Answer::Unsigned(6));
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use super::Directions;
+
+/// steps until all walkers are at an end node at the same time, moving them all one step at a time
+fn steps_to_end<'a>(
+    directions: &str,
+    map: &'a HashMap<String, Directions>,
+    mut nodes: Vec<&'a str>,
+    is_end: fn(&str) -> bool,
+) -> u64 {
+    let mut steps = 0;
+    for direction in directions.chars().cycle() {
+        if nodes.iter().all(|node| is_end(node)) {
+            break;
+        }
+        nodes = nodes
+            .into_iter()
+            .map(|node| match direction {
+                'L' => map[node].left.as_str(),
+                _ => map[node].right.as_str(),
+            })
+            .collect();
+        steps += 1;
+    }
+    steps
+}
+
+pub fn part_one(directions: &str, map: &HashMap<String, Directions>) -> u64 {
+    steps_to_end(directions, map, vec!["AAA"], |node| node == "ZZZ")
+}
+
+pub fn part_two(directions: &str, map: &HashMap<String, Directions>) -> u64 {
+    let starts = map
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(|node| node.as_str())
+        .collect();
+    steps_to_end(directions, map, starts, |node| node.ends_with('Z'))
+}
@@ -0,0 +1,152 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::parse;
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Vec<Vec<i32>>;
+
+    const PART_ONE: &'static str = "Sum of extrapolated values continuing series is {}";
+    const PART_TWO: &'static str = "Sum of extrapolated values before series is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<Vec<i32>>, AocError> {
+    let ctx = ParseContext::new(9, input_str);
+    let input = input_str
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                return Err(ctx.error(line, "expected a series of numbers"));
+            }
+            parse::numbers(&ctx, line)
+        })
+        .collect();
+    input
+}
+
+fn part_one(input: &Vec<Vec<i32>>) -> i32 {
+    let mut sum = 0;
+
+    for series in input {
+        sum += get_next_value(series);
+    }
+
+    sum
+}
+
+fn get_next_value(series: &Vec<i32>) -> i32 {
+    // if all values are the same (0 differences) next value is obvious
+    if series.iter().min() == series.iter().max() {
+        return series[0];
+    }
+
+    let differences: Vec<i32> = series.windows(2).into_iter().map(|w| w[1] - w[0]).collect();
+
+    return series.last().unwrap() + get_next_value(&differences);
+}
+
+fn part_two(input: &Vec<Vec<i32>>) -> i32 {
+    let mut sum = 0;
+
+    for series in input {
+        sum += get_previous_value(series);
+    }
+
+    sum
+}
+
+fn get_previous_value(series: &Vec<i32>) -> i32 {
+    // if all values are the same (0 differences) previous value is obvious
+    if series.iter().min() == series.iter().max() {
+        return series[0];
+    }
+
+    let differences: Vec<i32> = series.windows(2).into_iter().map(|w| w[1] - w[0]).collect();
+
+    return series.first().unwrap() - get_previous_value(&differences);
+}
+
+fn serialize_input(series: &[Vec<i32>]) -> String {
+    series
+        .iter()
+        .map(|values| {
+            let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+            values.join(" ") + "\n"
+        })
+        .collect()
+}
+
+/// values of polynomials up to the third degree with small coefficients
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size * 3 {
+        let degree = rng.gen_range(0..=3);
+        let coefficients: Vec<i32> = (0..=degree).map(|_| rng.gen_range(-5..=5)).collect();
+        let len = rng.gen_range(5..=size.min(20) + 5);
+        let values: Vec<String> = (0..len as i32)
+            .map(|x| {
+                let value = coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient);
+                value.to_string()
+            })
+            .collect();
+        input += &values.join(" ");
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day09.parse(EXAMPLE).unwrap();
+        assert_eq!(Day09.part_one(&input).unwrap(), Answer::Signed(114));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day09.parse(EXAMPLE).unwrap();
+        assert_eq!(Day09.part_two(&input).unwrap(), Answer::Signed(2));
+    }
+}
@@ -0,0 +1,27 @@
+/// (previous, next) value of a series, from the rows of differences down to one of only zeros
+fn extrapolate(series: &[i32]) -> (i32, i32) {
+    let mut rows = vec![series.to_vec()];
+    while rows
+        .last()
+        .is_some_and(|row| row.iter().any(|&value| value != 0))
+    {
+        let row = &rows[rows.len() - 1];
+        let differences = row.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        rows.push(differences);
+    }
+
+    rows.iter().rev().fold((0, 0), |(previous, next), row| {
+        (
+            row.first().copied().unwrap_or_default() - previous,
+            row.last().copied().unwrap_or_default() + next,
+        )
+    })
+}
+
+pub fn part_one(input: &[Vec<i32>]) -> i32 {
+    input.iter().map(|series| extrapolate(series).1).sum()
+}
+
+pub fn part_two(input: &[Vec<i32>]) -> i32 {
+    input.iter().map(|series| extrapolate(series).0).sum()
+}
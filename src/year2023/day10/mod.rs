@@ -0,0 +1,388 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::geometry::{Direction, Point};
+use crate::util::grid::Grid;
+use crate::util::image::{self, Image};
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Debug, Clone)]
+pub struct Pipe {
+    shape: char,
+    in_loop: bool,
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Grid<Pipe>;
+
+    const PART_ONE: &'static str = "Farthest distance is {}";
+    const PART_TWO: &'static str = "There are {} squares inside the loop";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(&mut input.clone())?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        // part 2 needs the loop marked (and the start replaced by its pipe) by part 1
+        let mut pipes = input.clone();
+        part_one(&mut pipes)?;
+        Ok(part_two(&pipes).0.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.render(|pipe| pipe.shape))
+    }
+
+    /// the loop as '*' and the squares inside it as 'X', and the same as a picture
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        let mut pipes = input.clone();
+        part_one(&mut pipes)?;
+        let map = part_two(&pipes).1;
+        let picture = Image::render(&map, 4, |&square| match square {
+            '*' => image::YELLOW,
+            'X' => image::GREEN,
+            _ => image::DARK_GRAY,
+        });
+        Ok(vec![
+            Artifact::text("loop.txt", map.to_string()),
+            picture.artifact("loop"),
+        ])
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Grid<Pipe>, AocError> {
+    let ctx = ParseContext::new(10, input_str);
+    let input = Grid::parse(&ctx, input_str, "a map of pipes", |c| {
+        if "|-LJ7F.S".contains(c) {
+            Ok(Pipe {
+                shape: c,
+                in_loop: false,
+            })
+        } else {
+            Err("unknown tile".to_string())
+        }
+    })?;
+
+    if input.iter().filter(|(_, pipe)| pipe.shape == 'S').count() != 1 {
+        return Err(ctx.error(input_str, "expected exactly one start tile"));
+    }
+    Ok(input)
+}
+
+/// shape at a position, everything outside the map is ground
+fn shape_at(input: &Grid<Pipe>, point: Point) -> char {
+    point
+        .to_position()
+        .and_then(|(row, col)| input.get(row, col))
+        .map_or('.', |pipe| pipe.shape)
+}
+
+fn part_one(input: &mut Grid<Pipe>) -> Result<u32, AocError> {
+    let mut steps = 0;
+
+    // find starting position
+    let start = input
+        .position(|pipe| pipe.shape == 'S')
+        .ok_or_else(|| AocError::solve(10, "there is no start tile"))?;
+    let mut position = Point::from_position(start);
+
+    // find starting direction
+    let mut current_dir = Direction::ALL
+        .into_iter()
+        .find(|&dir| get_direction(shape_at(input, position + dir.delta()), dir).is_some())
+        .ok_or_else(|| AocError::solve(10, "no pipe connects to the start"))?;
+    position += current_dir.delta();
+
+    loop {
+        steps += 1;
+
+        current_dir = match get_direction(shape_at(input, position), current_dir) {
+            Some(dir) => dir,
+            None => {
+                return Err(AocError::solve(
+                    10,
+                    "reached a dead end, the loop is not closed",
+                ))
+            }
+        };
+        input[(position.y as usize, position.x as usize)].in_loop = true;
+
+        position += current_dir.delta();
+
+        if shape_at(input, position) == 'S' {
+            // once found, replace S with the pipe it represents, important for part 2
+            let above = shape_at(input, position.step(Direction::Up, 1));
+            let below = shape_at(input, position.step(Direction::Down, 1));
+            let left = shape_at(input, position.step(Direction::Left, 1));
+            let right = shape_at(input, position.step(Direction::Right, 1));
+
+            let loop_above = above == '|' || above == '7' || above == 'F';
+            let loop_below = below == '|' || below == 'J' || below == 'L';
+            let loop_left = left == '-' || left == 'F' || left == 'L';
+            let loop_right = right == '-' || right == '7' || right == 'J';
+
+            let start_shape = match (loop_above, loop_below, loop_left, loop_right) {
+                (true, true, false, false) => '|',
+                (true, false, true, false) => 'J',
+                (true, false, false, true) => 'L',
+                (false, true, true, false) => '7',
+                (false, true, false, true) => 'F',
+                (false, false, true, true) => '-',
+                (_, _, _, _) => '?',
+            };
+
+            input[(position.y as usize, position.x as usize)].shape = start_shape;
+            input[(position.y as usize, position.x as usize)].in_loop = true;
+
+            break;
+        }
+    }
+
+    Ok((steps / 2) + 1)
+}
+
+/// squares inside the loop, and a map showing them
+fn part_two(input: &Grid<Pipe>) -> (u32, Grid<char>) {
+    let mut inside_squares = 0;
+    let mut visual_output = input.map(|pipe| pipe.shape);
+
+    for (r_idx, row) in input.rows().enumerate() {
+        let mut inside_loop = false; // at the start of a row, we can never be inside the loop
+        for (c_idx, pipe) in row.iter().enumerate() {
+            // not loop and loop must be separated by "ascending" pipes
+            if pipe.in_loop && (pipe.shape == '|' || pipe.shape == 'J' || pipe.shape == 'L') {
+                inside_loop = !inside_loop;
+            } else if inside_loop && !pipe.in_loop {
+                inside_squares += 1;
+            }
+
+            visual_output[(r_idx, c_idx)] = match (pipe.in_loop, inside_loop) {
+                (true, _) => '*',
+                (false, true) => 'X',
+                (false, false) => ' ',
+            };
+        }
+    }
+
+    (inside_squares, visual_output)
+}
+
+/// direction after passing through `pipe`, None if the pipe doesn't connect to where we came from
+fn get_direction(pipe: char, incoming_direction: Direction) -> Option<Direction> {
+    match (pipe, incoming_direction) {
+        ('|', Direction::Down | Direction::Up) => Some(incoming_direction),
+        ('-', Direction::Right | Direction::Left) => Some(incoming_direction),
+        ('L', Direction::Down) => Some(Direction::Right),
+        ('L', Direction::Left) => Some(Direction::Up),
+        ('J', Direction::Down) => Some(Direction::Left),
+        ('J', Direction::Right) => Some(Direction::Up),
+        ('7', Direction::Up) => Some(Direction::Left),
+        ('7', Direction::Right) => Some(Direction::Down),
+        ('F', Direction::Up) => Some(Direction::Right),
+        ('F', Direction::Left) => Some(Direction::Down),
+        (_, _) => None,
+    }
+}
+
+/// the outline of a random blob of squares, on a map twice as fine with junk pipes everywhere else
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    const JUNK: &[u8] = b"|-LJ7F.....";
+    let side = size.max(2);
+    let mut blob = Grid::filled(side, side, false);
+    let mut squares = vec![(side / 2, side / 2)];
+    blob[squares[0]] = true;
+    let mut outlined = outline(&blob).expect("a single square is outlined by a loop");
+
+    for _ in 0..side * side * 2 {
+        let square = squares[rng.gen_range(0..squares.len())];
+        let Some(next) = blob.step(square, Direction::ALL[rng.gen_range(0..4)]) else {
+            continue;
+        };
+        if blob[next] {
+            continue;
+        }
+        // squares that close a hole or only touch the blob at a corner would make the outline cross itself
+        blob[next] = true;
+        match outline(&blob) {
+            Some(map) => {
+                outlined = map;
+                squares.push(next);
+            }
+            None => blob[next] = false,
+        }
+    }
+
+    let loop_positions: Vec<(usize, usize)> = outlined
+        .iter()
+        .filter(|(_, &shape)| shape != '.')
+        .map(|(position, _)| position)
+        .collect();
+    let start = loop_positions[rng.gen_range(0..loop_positions.len())];
+    let start_neighbors: Vec<(usize, usize)> = outlined.neighbors4(start).collect();
+
+    Grid::from_fn(outlined.width(), outlined.height(), |position| {
+        if position == start {
+            'S'
+        } else if outlined[position] != '.' {
+            outlined[position]
+        } else if start_neighbors.contains(&position) {
+            // junk must not connect to the start, only the two pipes of the loop do
+            '.'
+        } else {
+            JUNK[rng.gen_range(0..JUNK.len())] as char
+        }
+    })
+    .to_string()
+}
+
+/// pipes around a blob of squares on a map twice as fine, None unless they form a single loop
+fn outline(blob: &Grid<bool>) -> Option<Grid<char>> {
+    let (width, height) = (blob.width() * 2 + 1, blob.height() * 2 + 1);
+    let mut connections: Grid<Vec<Direction>> = Grid::filled(width, height, vec![]);
+
+    for ((row, col), _) in blob.iter().filter(|(_, &inside)| inside) {
+        let center = Point::new(2 * col as i64 + 1, 2 * row as i64 + 1);
+        for direction in Direction::ALL {
+            if blob
+                .step((row, col), direction)
+                .is_some_and(|neighbor| blob[neighbor])
+            {
+                continue;
+            }
+            // a pipe runs along the side of the square from corner to corner
+            let side = center + direction.delta();
+            for along in [direction.turn_left(), direction.turn_right()] {
+                let corner = side + along.delta();
+                connections[(side.y as usize, side.x as usize)].push(along);
+                connections[(corner.y as usize, corner.x as usize)].push(along.reverse());
+            }
+        }
+    }
+
+    // every pipe connects two ways, and following them from any pipe passes all of them
+    if connections
+        .iter()
+        .any(|(_, pipe)| !pipe.is_empty() && pipe.len() != 2)
+    {
+        return None;
+    }
+    let pipe_count = connections
+        .iter()
+        .filter(|(_, pipe)| !pipe.is_empty())
+        .count();
+    let start = connections.position(|pipe| !pipe.is_empty())?;
+    let mut position = start;
+    let mut direction = connections[start][0];
+    let mut length = 0;
+    loop {
+        position = connections.step(position, direction)?;
+        length += 1;
+        if position == start {
+            break;
+        }
+        direction = *connections[position]
+            .iter()
+            .find(|&&next| next != direction.reverse())?;
+    }
+    if length != pipe_count {
+        return None;
+    }
+
+    Some(connections.map(|pipe| {
+        let mut pipe = pipe.clone();
+        pipe.sort();
+        match pipe[..] {
+            [Direction::Right, Direction::Down] => 'F',
+            [Direction::Right, Direction::Left] => '-',
+            [Direction::Right, Direction::Up] => 'L',
+            [Direction::Down, Direction::Left] => '7',
+            [Direction::Down, Direction::Up] => '|',
+            [Direction::Left, Direction::Up] => 'J',
+            _ => '.',
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::image::ImageFormat;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+    const EXAMPLE_2: &str = include_str!("example_input_2.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("example_input_part_two.txt");
+    const EXAMPLE_PART_TWO_2: &str = include_str!("example_input_part_two_2.txt");
+    const EXAMPLE_PART_TWO_3: &str = include_str!("example_input_part_two_3.txt");
+    const EXAMPLE_PART_TWO_4: &str = include_str!("example_input_part_two_4.txt");
+
+    fn solve(part: u8, input_str: &str) -> Answer {
+        let input = Day10.parse(input_str).unwrap();
+        match part {
+            1 => Day10.part_one(&input).unwrap(),
+            _ => Day10.part_two(&input).unwrap(),
+        }
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(solve(1, EXAMPLE), Answer::Unsigned(4));
+    }
+
+    #[test]
+    fn part_one_complex_loop() {
+        assert_eq!(solve(1, EXAMPLE_2), Answer::Unsigned(8));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(solve(2, EXAMPLE_PART_TWO), Answer::Unsigned(4));
+    }
+
+    #[test]
+    fn part_two_squeezing_between_pipes() {
+        assert_eq!(solve(2, EXAMPLE_PART_TWO_2), Answer::Unsigned(4));
+    }
+
+    #[test]
+    fn part_two_larger_example() {
+        assert_eq!(solve(2, EXAMPLE_PART_TWO_3), Answer::Unsigned(8));
+    }
+
+    #[test]
+    fn part_two_junk_pipes() {
+        assert_eq!(solve(2, EXAMPLE_PART_TWO_4), Answer::Unsigned(10));
+    }
+
+    #[test]
+    fn emits_map_of_the_loop() {
+        let input = Day10.parse(EXAMPLE_PART_TWO_4).unwrap();
+        let artifacts = Day10.emit(&input).unwrap();
+        assert_eq!(artifacts[0].name, "loop.txt");
+        let (_, map) = artifacts[0].encode(ImageFormat::Png);
+        let map = String::from_utf8(map.into_owned()).unwrap();
+        assert_eq!(map.matches('X').count(), 10);
+        assert!(!map.contains('S'));
+        assert_eq!(artifacts[1].encode(ImageFormat::Png).0, "loop.png");
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::VecDeque;
+
+use super::Pipe;
+use crate::util::grid::Grid;
+
+/// (row, column) offsets of the two tiles a pipe connects
+fn connections(shape: char) -> &'static [(isize, isize)] {
+    match shape {
+        '|' => &[(-1, 0), (1, 0)],
+        '-' => &[(0, -1), (0, 1)],
+        'L' => &[(-1, 0), (0, 1)],
+        'J' => &[(-1, 0), (0, -1)],
+        '7' => &[(1, 0), (0, -1)],
+        'F' => &[(1, 0), (0, 1)],
+        _ => &[],
+    }
+}
+
+/// the shapes with the start replaced by the pipe that closes the loop through it, and the tiles of that loop
+fn find_loop(pipes: &Grid<Pipe>) -> (Grid<char>, Vec<(usize, usize)>) {
+    let start = pipes
+        .position(|pipe| pipe.shape == 'S')
+        .expect("checked by the parser");
+    "|-LJ7F"
+        .chars()
+        .find_map(|start_shape| {
+            let mut shapes = pipes.map(|pipe| pipe.shape);
+            shapes[start] = start_shape;
+            follow(&shapes, start).map(|tiles| (shapes, tiles))
+        })
+        .expect("the start is on a loop")
+}
+
+/// tiles from `start` around to it again, None if a pipe on the way isn't connected on both ends
+fn follow(shapes: &Grid<char>, start: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+    let mut tiles = vec![];
+    let (mut previous, mut current) = (None, start);
+    loop {
+        let connected: Vec<(usize, usize)> = connections(shapes[current])
+            .iter()
+            .map(|&(d_row, d_col)| {
+                let next = shapes.offset(current, (d_row, d_col))?;
+                connections(shapes[next])
+                    .contains(&(-d_row, -d_col))
+                    .then_some(next)
+            })
+            .collect::<Option<_>>()?;
+        tiles.push(current);
+
+        let next = connected.into_iter().find(|&next| Some(next) != previous)?;
+        (previous, current) = (Some(current), next);
+        if current == start {
+            return Some(tiles);
+        }
+    }
+}
+
+pub fn part_one(pipes: &Grid<Pipe>) -> usize {
+    find_loop(pipes).1.len() / 2
+}
+
+/// floods the outside of the loop drawn three times as large, so it can squeeze between pipes
+pub fn part_two(pipes: &Grid<Pipe>) -> usize {
+    let (shapes, tiles) = find_loop(pipes);
+    let mut on_loop = Grid::filled(shapes.width(), shapes.height(), false);
+    let mut walls = Grid::filled(shapes.width() * 3, shapes.height() * 3, false);
+    for &(row, col) in &tiles {
+        on_loop[(row, col)] = true;
+        let center = (row * 3 + 1, col * 3 + 1);
+        walls[center] = true;
+        for &offset in connections(shapes[(row, col)]) {
+            let side = walls.offset(center, offset).expect("inside the tile");
+            walls[side] = true;
+        }
+    }
+
+    // the top left corner of a tile is never part of a pipe
+    let mut outside = Grid::filled(walls.width(), walls.height(), false);
+    let mut queue = VecDeque::from([(0, 0)]);
+    outside[(0, 0)] = true;
+    while let Some(position) = queue.pop_front() {
+        for next in walls.neighbors4(position) {
+            if !walls[next] && !outside[next] {
+                outside[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    shapes
+        .positions()
+        .filter(|&(row, col)| !on_loop[(row, col)] && !outside[(row * 3 + 1, col * 3 + 1)])
+        .count()
+}
@@ -0,0 +1,191 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::grid::Grid;
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Grid<char>;
+
+    const PART_ONE: &'static str = "Sum of distance between galaxies is {}";
+    const PART_TWO: &'static str = "When expanding more, sum is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(&expand_space(input)).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(&expand_space_more(input)).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.to_string())
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Grid<char>, AocError> {
+    let ctx = ParseContext::new(11, input_str);
+    Grid::parse_chars(&ctx, input_str, ".#", "an image of the sky")
+}
+
+fn expand_space(input: &Grid<char>) -> Grid<char> {
+    // expand rows, then columns (which are the rows of the transposed map)
+    double_empty_rows(&double_empty_rows(input).transpose()).transpose()
+}
+
+/// inserts every row without galaxies twice
+fn double_empty_rows(input: &Grid<char>) -> Grid<char> {
+    let mut cells = vec![];
+    let mut height = 0;
+
+    for row in input.rows() {
+        let copies = if row.iter().all(|c| *c == '.') { 2 } else { 1 };
+        for _ in 0..copies {
+            cells.extend_from_slice(row);
+            height += 1;
+        }
+    }
+
+    Grid::new(input.width(), height, cells)
+}
+
+fn expand_space_more(input: &Grid<char>) -> Grid<char> {
+    let mut space_map = input.clone();
+
+    // expand rows
+    for row in 0..input.height() {
+        if input.row(row).iter().all(|c| *c == '.') {
+            // mark row as expanded vertically
+            for col in 0..input.width() {
+                space_map[(row, col)] = '|';
+            }
+        }
+    }
+
+    // expand columns
+    for col in 0..space_map.width() {
+        if space_map.column(col).all(|c| *c != '#') {
+            for row in 0..space_map.height() {
+                let cell = &mut space_map[(row, col)];
+                *cell = if *cell == '|' {
+                    // mark cell as expanded in both directions
+                    '+'
+                } else {
+                    // mark cell as expanded horizontally
+                    '-'
+                };
+            }
+        }
+    }
+
+    space_map
+}
+
+/// (column, row) of every galaxy
+fn galaxy_coordinates(space_map: &Grid<char>) -> Vec<(usize, usize)> {
+    space_map
+        .iter()
+        .filter(|(_, space)| **space == '#')
+        .map(|((r_idx, c_idx), _)| (c_idx, r_idx))
+        .collect()
+}
+
+fn part_one(space_map: &Grid<char>) -> usize {
+    let galaxy_coordinates = galaxy_coordinates(space_map);
+
+    let mut distance_sum = 0;
+    for (i, from_galaxy) in galaxy_coordinates.iter().enumerate() {
+        for to_galaxy in galaxy_coordinates[(i + 1)..].iter() {
+            let x_distance = to_galaxy.0.abs_diff(from_galaxy.0);
+            let y_distance = to_galaxy.1.abs_diff(from_galaxy.1);
+            distance_sum += x_distance + y_distance;
+        }
+    }
+
+    distance_sum
+}
+
+fn part_two(space_map: &Grid<char>) -> usize {
+    let galaxy_coordinates = galaxy_coordinates(space_map);
+
+    let mut distance_sum = 0;
+    for (i, from_galaxy) in galaxy_coordinates.iter().enumerate() {
+        for to_galaxy in galaxy_coordinates[(i + 1)..].iter() {
+            let from_row = from_galaxy.1.min(to_galaxy.1) as i32;
+            let to_row = from_galaxy.1.max(to_galaxy.1) as i32;
+            let from_col = from_galaxy.0.min(to_galaxy.0) as i32;
+            let to_col = from_galaxy.0.max(to_galaxy.0) as i32;
+
+            for row in (from_row + 1)..(to_row + 1) {
+                distance_sum += match space_map[(row as usize, from_galaxy.0)] {
+                    '|' | 'X' => 1000000,
+                    _ => 1,
+                }
+            }
+
+            for col in (from_col + 1)..(to_col + 1) {
+                distance_sum += match space_map[(from_galaxy.1, col as usize)] {
+                    '-' | 'X' => 1000000,
+                    _ => 1,
+                }
+            }
+        }
+    }
+
+    distance_sum
+}
+
+/// galaxies scattered over the sky, with some rows and columns left empty to expand
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let side = size * 2;
+    let empty_rows: Vec<bool> = (0..side).map(|_| rng.gen_bool(0.2)).collect();
+    let empty_cols: Vec<bool> = (0..side).map(|_| rng.gen_bool(0.2)).collect();
+    Grid::from_fn(side, side, |(row, col)| {
+        if !empty_rows[row] && !empty_cols[col] && rng.gen_bool(0.15) {
+            '#'
+        } else {
+            '.'
+        }
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(Day11.part_one(&input).unwrap(), Answer::Unsigned(374));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(Day11.part_two(&input).unwrap(), Answer::Unsigned(82000210));
+    }
+}
@@ -0,0 +1,38 @@
+use crate::util::grid::Grid;
+
+/// sum of the distances between all pairs of galaxies, with every empty row and column `factor` times as wide
+fn distance_sum(sky: &Grid<char>, factor: usize) -> usize {
+    let empty_rows: Vec<bool> = sky.rows().map(|row| !row.contains(&'#')).collect();
+    let empty_cols: Vec<bool> = sky
+        .columns()
+        .map(|mut col| col.all(|&c| c != '#'))
+        .collect();
+    let galaxies: Vec<(usize, usize)> = sky
+        .iter()
+        .filter(|(_, &c)| c == '#')
+        .map(|(position, _)| position)
+        .collect();
+
+    // going from one galaxy to the other, every row and column passed on the way counts with its width
+    let width = |empty: &[bool], from: usize, to: usize| -> usize {
+        (from.min(to) + 1..=from.max(to))
+            .map(|idx| if empty[idx] { factor } else { 1 })
+            .sum()
+    };
+
+    let mut sum = 0;
+    for (idx, from) in galaxies.iter().enumerate() {
+        for to in &galaxies[idx + 1..] {
+            sum += width(&empty_rows, from.0, to.0) + width(&empty_cols, from.1, to.1);
+        }
+    }
+    sum
+}
+
+pub fn part_one(sky: &Grid<char>) -> usize {
+    distance_sum(sky, 2)
+}
+
+pub fn part_two(sky: &Grid<char>) -> usize {
+    distance_sum(sky, 1_000_000)
+}
@@ -0,0 +1,213 @@
+use cached::proc_macro::cached;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Debug)]
+pub struct SpringData {
+    data_str: String,
+    groups: Vec<u32>,
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Vec<SpringData>;
+
+    const PART_ONE: &'static str = "{} options for broken spring configurations";
+    const PART_TWO: &'static str = "{} options for unfolded data";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(&unfold_data(input)).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<SpringData>, AocError> {
+    let ctx = ParseContext::new(12, input_str);
+    input_str
+        .lines()
+        .map(|line| {
+            let (data, group_str) = ctx.split_once(line, " ")?;
+            if let Some(idx) = data.find(|c| !".#?".contains(c)) {
+                return Err(ctx.error(&data[idx..], "expected '.', '#' or '?'"));
+            }
+            let groups = group_str
+                .split(",")
+                .map(|c| ctx.number(c))
+                .collect::<Result<_, _>>()?;
+            Ok(SpringData {
+                data_str: data.to_string(),
+                groups: groups,
+            })
+        })
+        .collect()
+}
+
+fn unfold_data(input: &Vec<SpringData>) -> Vec<SpringData> {
+    input
+        .iter()
+        .map(|data| SpringData {
+            data_str: (data.data_str.to_string() + "?").repeat(4) + &data.data_str,
+            groups: data.groups[..].repeat(5),
+        })
+        .collect()
+}
+
+fn part_one_two(input: &[SpringData]) -> u64 {
+    let mut option_sum = 0;
+    for data in input.iter() {
+        let result = valid_option_count(data.data_str.to_string(), data.groups.clone());
+        option_sum += result as u64;
+    }
+    option_sum
+}
+
+// turns out Rust can do the caching for you!
+#[cached]
+fn valid_option_count(mut data_str: String, groups: Vec<u32>) -> u64 {
+    // remove leading or trailing periods
+    data_str = data_str.trim_matches('.').to_string();
+
+    if groups.is_empty() {
+        if !data_str.contains("#") {
+            // groups empty, string done, great
+            return 1;
+        } else {
+            // still broken springs, but no more groups!
+            return 0;
+        }
+    }
+
+    // string is done and we're looking for more groups, can't succeed
+    if data_str.is_empty() {
+        return 0;
+    }
+
+    // if group started (# at beginning of string): try to complete group
+    // completing group is impossible if:
+    // - string too short
+    // - periods in group length
+    // - # where group should be over
+    if data_str.starts_with("#") {
+        if data_str.len() < groups[0] as usize
+            || data_str[..groups[0] as usize].chars().any(|c| c == '.')
+            || data_str.chars().nth(groups[0] as usize) == Some('#')
+        {
+            return 0;
+        } else {
+            // completing group is possible, cut out this group and check the rest
+            if groups.len() > 1 {
+                if data_str.len() > groups[0] as usize {
+                    // string from start to group[0]+1 is forced ({#}.)
+                    data_str = data_str[(groups[0] + 1) as usize..].to_string();
+                    return valid_option_count(data_str, groups[1..].to_vec());
+                } else {
+                    // need to keep searching, but string is too short
+                    return 0;
+                }
+            } else {
+                // no more groups, but remaining string still needs to be checked (may have extra #s)
+                // don't continue from groups[0]+1 as that index may not exist (could be ., could be end of string)
+                data_str = data_str[groups[0] as usize..].to_string();
+                return valid_option_count(data_str, groups[1..].to_vec());
+            }
+        }
+    }
+
+    // no question marks means nothing to replace, just need to finish going through
+    if !data_str.contains("?") {
+        return valid_option_count(data_str[1..].to_string(), groups);
+    }
+
+    // try both options for ?
+    let working_str = data_str.replacen("?", ".", 1);
+    let broken_str = data_str.replacen("?", "#", 1);
+    let valid_count = valid_option_count(working_str, groups.clone())
+        + valid_option_count(broken_str, groups.clone());
+
+    return valid_count;
+}
+
+fn serialize_input(rows: &[SpringData]) -> String {
+    rows.iter()
+        .map(|row| {
+            let groups: Vec<String> = row.groups.iter().map(|group| group.to_string()).collect();
+            format!("{} {}\n", row.data_str, groups.join(","))
+        })
+        .collect()
+}
+
+/// rows with some springs unknown, the groups come from the full row so there is always an arrangement
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size * 3 {
+        let len = rng.gen_range(1..=size.min(14) + 2);
+        let mut broken: Vec<bool> = (0..len).map(|_| rng.gen_bool(0.5)).collect();
+        if !broken.contains(&true) {
+            broken[rng.gen_range(0..len)] = true;
+        }
+
+        let groups: Vec<String> = broken
+            .split(|&is_broken| !is_broken)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let data: String = broken
+            .iter()
+            .map(|&is_broken| match (rng.gen_bool(0.4), is_broken) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect();
+        input += &format!("{data} {}\n", groups.join(","));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part_one(&input).unwrap(), Answer::Unsigned(21));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part_two(&input).unwrap(), Answer::Unsigned(525152));
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use super::SpringData;
+
+/// sizes of the groups of broken springs in a row without unknowns
+fn groups(springs: &[u8]) -> Vec<u32> {
+    springs
+        .split(|&spring| spring != b'#')
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len() as u32)
+        .collect()
+}
+
+/// tries both states for every unknown spring
+fn all_arrangements(springs: &mut [u8], expected: &[u32]) -> u64 {
+    let Some(unknown) = springs.iter().position(|&spring| spring == b'?') else {
+        return (groups(springs) == expected) as u64;
+    };
+    let mut count = 0;
+    for state in [b'.', b'#'] {
+        springs[unknown] = state;
+        count += all_arrangements(springs, expected);
+    }
+    springs[unknown] = b'?';
+    count
+}
+
+pub fn part_one(rows: &[SpringData]) -> u64 {
+    rows.iter()
+        .map(|row| all_arrangements(&mut row.data_str.clone().into_bytes(), &row.groups))
+        .sum()
+}
+
+/// the unfolded rows have far too many arrangements to try them all, so this goes spring by spring and only
+/// keeps how many arrangements got to each (groups finished, length of the group being read) so far
+fn count_arrangements(springs: &[u8], expected: &[u32]) -> u64 {
+    let mut ways: HashMap<(usize, u32), u64> = HashMap::from([((0, 0), 1)]);
+    for &spring in springs {
+        let mut next: HashMap<(usize, u32), u64> = HashMap::new();
+        for (&(done, length), &count) in &ways {
+            if spring != b'#' {
+                // working, which ends the group if there is one
+                if length == 0 {
+                    *next.entry((done, 0)).or_default() += count;
+                } else if expected.get(done) == Some(&length) {
+                    *next.entry((done + 1, 0)).or_default() += count;
+                }
+            }
+            if spring != b'.' && expected.get(done).is_some_and(|&group| length < group) {
+                // broken, which makes the group longer
+                *next.entry((done, length + 1)).or_default() += count;
+            }
+        }
+        ways = next;
+    }
+
+    ways.into_iter()
+        .filter(|&((done, length), _)| {
+            (done == expected.len() && length == 0)
+                || (done + 1 == expected.len() && expected[done] == length)
+        })
+        .map(|(_, count)| count)
+        .sum()
+}
+
+pub fn part_two(rows: &[SpringData]) -> u64 {
+    rows.iter()
+        .map(|row| {
+            let springs = [row.data_str.as_str(); 5].join("?");
+            count_arrangements(springs.as_bytes(), &row.groups.repeat(5))
+        })
+        .sum()
+}
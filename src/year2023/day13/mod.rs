@@ -0,0 +1,273 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::grid::Grid;
+use crate::{Answer, AocError, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Grid<char>>;
+
+    const PART_ONE: &'static str = "Sum of adjusted reflection lines is {}";
+    const PART_TWO: &'static str = "With smudges fixed, sum is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<Grid<char>>, AocError> {
+    let ctx = ParseContext::new(13, input_str);
+
+    input_str
+        .trim_end()
+        .split("\n\n")
+        .map(|block| Grid::parse_chars(&ctx, block, ".#", "a pattern"))
+        .collect()
+}
+
+fn part_one(input: &[Grid<char>]) -> Result<usize, AocError> {
+    input
+        .iter()
+        .map(|map| find_reflection(map, false, 0).ok_or_else(|| no_reflection(map)))
+        .sum()
+}
+
+fn part_two(input: &[Grid<char>]) -> Result<usize, AocError> {
+    let mut reflection_sum = 0;
+    for map in input {
+        // original reflection to check against later
+        let reflection_line = find_reflection(map, false, 0).ok_or_else(|| no_reflection(map))?;
+        let mut success = false;
+        // try out each coordinate for fixing smudge
+        for row in 0..map.height() {
+            for col in 0..map.width() {
+                let mut new_map = map.clone();
+                new_map[(row, col)] = match new_map[(row, col)] {
+                    '.' => '#',
+                    _ => '.',
+                };
+                // check if there is now a new reflection line
+                match find_reflection(&new_map, true, reflection_line) {
+                    Some(result) => {
+                        success = true;
+                        reflection_sum += result;
+                        break;
+                    }
+                    None => {}
+                }
+            }
+            if success {
+                break;
+            }
+        }
+        if !success {
+            return Err(AocError::solve(
+                13,
+                format!("couldn't fix smudge for map:\n{map}"),
+            ));
+        }
+    }
+
+    Ok(reflection_sum)
+}
+
+fn no_reflection(map: &Grid<char>) -> AocError {
+    AocError::solve(13, format!("no reflection found in map:\n{map}"))
+}
+
+/// finds reflection in map, either horizontally or vertically
+/// returns line nr after reflection for vertical, line nr after reflection * 100 for horizontal
+fn find_reflection(map: &Grid<char>, part2: bool, cannot_be: usize) -> Option<usize> {
+    // check horizontally
+    // check for duplicated lines, then verify whether it's a complete reflection for found pairs
+    let horizontal_matches: Vec<usize> = (1..map.height())
+        .filter(|&idx| map.row(idx - 1) == map.row(idx) && verify_reflection(idx, map))
+        .collect();
+
+    if !horizontal_matches.is_empty() {
+        // check each found reflection line against the line it's not allowed to be for part 2
+        // if part2 is false this will simply return the first (and presumably only) reflection line found
+        for h_match in horizontal_matches {
+            if !part2 || (h_match * 100) != cannot_be {
+                return Some(h_match * 100);
+            }
+        }
+    }
+
+    // check vertically
+    // transpose map and repeat horizontal process
+    let map = map.transpose();
+
+    let vertical_matches: Vec<usize> = (1..map.height())
+        .filter(|&idx| map.row(idx - 1) == map.row(idx) && verify_reflection(idx, &map))
+        .collect();
+
+    if !vertical_matches.is_empty() {
+        // check each found reflection line against the line it's not allowed to be for part 2
+        // if part2 is false this will simply return the first (and presumably only) reflection line found
+        for v_match in vertical_matches {
+            if !part2 || v_match != cannot_be {
+                return Some(v_match);
+            }
+        }
+    }
+
+    None
+}
+
+fn verify_reflection(index: usize, map: &Grid<char>) -> bool {
+    for i in 0..map.height() {
+        if (index as i32 - 1 - i as i32) < 0 || index + i >= map.height() {
+            // reached top or bottom end of map without finding line that isn't reflected
+            return true;
+        }
+
+        // compare two lines, moving upwards and downwards from the initial pair
+        if map.row(index + i) != map.row(index - 1 - i) {
+            // rows don't match, not a true reflection
+            return false;
+        }
+    }
+
+    // made it all the way through the map without finding a line that isn't reflected
+    true
+}
+
+fn serialize_input(patterns: &[Grid<char>]) -> String {
+    let patterns: Vec<String> = patterns.iter().map(|pattern| pattern.to_string()).collect();
+    patterns.join("\n")
+}
+
+/// patterns with a reflection, and a smudge that hides a second one
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size)
+        .map(|_| generate_pattern(rng, size).to_string())
+        .collect();
+    patterns.join("\n")
+}
+
+/// pattern that reflects along a row line and a column line, then gets a smudge that breaks the column reflection
+/// the smudge is in a row without a mirror image, so the row reflection stays
+/// random cells can line up into more reflections, so this tries again until the pattern is unambiguous
+fn generate_pattern(rng: &mut StdRng, size: usize) -> Grid<char> {
+    loop {
+        let pattern = smudged_pattern(rng, size);
+        if unambiguous(&pattern) {
+            return pattern;
+        }
+    }
+}
+
+fn smudged_pattern(rng: &mut StdRng, size: usize) -> Grid<char> {
+    let height = rng.gen_range(3..=size + 6);
+    let width = rng.gen_range(2..=size + 6);
+    let row_line = loop {
+        let line = rng.gen_range(1..height);
+        if 2 * line != height {
+            break line;
+        }
+    };
+    let col_line = rng.gen_range(1..width);
+    // index on the other side of a line between line - 1 and line, if it's still on the pattern
+    let mirror = |idx: usize, line: usize, len: usize| {
+        (2 * line)
+            .checked_sub(idx + 1)
+            .filter(|&mirrored| mirrored < len)
+    };
+
+    let cells = Grid::from_fn(width, height, |_| if rng.gen_bool(0.5) { '#' } else { '.' });
+    let mut pattern = Grid::from_fn(width, height, |(row, col)| {
+        let row = mirror(row, row_line, height).map_or(row, |mirrored| mirrored.min(row));
+        let col = mirror(col, col_line, width).map_or(col, |mirrored| mirrored.min(col));
+        cells[(row, col)]
+    });
+
+    let rows: Vec<usize> = (0..height)
+        .filter(|&row| mirror(row, row_line, height).is_none())
+        .collect();
+    let cols: Vec<usize> = (0..width)
+        .filter(|&col| mirror(col, col_line, width).is_some())
+        .collect();
+    let smudge = (
+        rows[rng.gen_range(0..rows.len())],
+        cols[rng.gen_range(0..cols.len())],
+    );
+    pattern[smudge] = if pattern[smudge] == '#' { '.' } else { '#' };
+
+    if rng.gen_bool(0.5) {
+        pattern.transpose()
+    } else {
+        pattern
+    }
+}
+
+/// exactly one line reflects the pattern and exactly one other line would with one cell fixed, like in the puzzle
+fn unambiguous(pattern: &Grid<char>) -> bool {
+    let columns = pattern.transpose();
+    let differences: Vec<usize> = [pattern, &columns]
+        .into_iter()
+        .flat_map(|grid| {
+            (1..grid.height()).map(move |line| {
+                (0..line.min(grid.height() - line))
+                    .map(|offset| {
+                        grid.row(line - 1 - offset)
+                            .iter()
+                            .zip(grid.row(line + offset))
+                            .filter(|(a, b)| a != b)
+                            .count()
+                    })
+                    .sum()
+            })
+        })
+        .collect();
+    differences.iter().filter(|&&count| count == 0).count() == 1
+        && differences.iter().filter(|&&count| count == 1).count() == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part_one(&input).unwrap(), Answer::Unsigned(405));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part_two(&input).unwrap(), Answer::Unsigned(400));
+    }
+}
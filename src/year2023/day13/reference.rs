@@ -0,0 +1,34 @@
+use crate::util::grid::Grid;
+
+/// cells that don't match their mirror image when reflecting the pattern between row `line` - 1 and `line`
+fn differences(pattern: &Grid<char>, line: usize) -> usize {
+    (0..line.min(pattern.height() - line))
+        .map(|offset| {
+            pattern
+                .row(line - 1 - offset)
+                .iter()
+                .zip(pattern.row(line + offset))
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
+
+/// 100 times the rows above a reflection line with exactly `smudges` differences, or else the columns left of one
+fn summary(pattern: &Grid<char>, smudges: usize) -> usize {
+    let columns = pattern.transpose();
+    (1..pattern.height())
+        .find(|&line| differences(pattern, line) == smudges)
+        .map(|line| 100 * line)
+        .or_else(|| (1..columns.height()).find(|&line| differences(&columns, line) == smudges))
+        .expect("every pattern has a reflection")
+}
+
+pub fn part_one(patterns: &[Grid<char>]) -> usize {
+    patterns.iter().map(|pattern| summary(pattern, 0)).sum()
+}
+
+/// the smudge is the one cell that doesn't match across the new reflection line
+pub fn part_two(patterns: &[Grid<char>]) -> usize {
+    patterns.iter().map(|pattern| summary(pattern, 1)).sum()
+}
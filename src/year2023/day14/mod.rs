@@ -0,0 +1,241 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::cycle::{self, Cycle};
+use crate::util::grid::Grid;
+use crate::util::image::{self, Image};
+use crate::{Answer, AocError, Artifact, Frame, ParseContext, Solver, Stepper};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Grid<char>;
+
+    const PART_ONE: &'static str = "Total load after tilting north is {}";
+    const PART_TWO: &'static str = "After 1000000000 spin cycles, load is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(&mut input.clone()).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.to_string())
+    }
+
+    /// the platform after each spin cycle until it starts going in circles
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(image::frames("spin", spin_frames(input)))
+    }
+
+    /// one spin cycle per step
+    fn stepper(&self, input: &Self::Input) -> Option<Box<dyn Stepper>> {
+        Some(Box::new(Spins::new(input)))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Grid<char>, AocError> {
+    let ctx = ParseContext::new(14, input_str);
+    Grid::parse_chars(&ctx, input_str, ".#O", "a platform")
+}
+
+fn part_one(platform: &mut Grid<char>) -> usize {
+    // tilt north
+    for row_idx in 0..platform.height() {
+        for col_idx in 0..platform.width() {
+            if platform[(row_idx, col_idx)] == 'O' {
+                move_north(platform, row_idx, col_idx);
+            }
+        }
+    }
+
+    calculate_load(platform)
+}
+
+fn part_two(input: &Grid<char>) -> usize {
+    // the platform soon ends up going in circles, so most of the spin cycles can be skipped
+    let platform = cycle::state_at(
+        input.clone(),
+        |platform| do_spin_cycle(platform.clone()),
+        1000000000,
+    );
+
+    calculate_load(&platform)
+}
+
+fn spin_frames(input: &Grid<char>) -> Vec<Image> {
+    // the real input takes about a hundred spins, anything above that isn't worth looking at
+    const MAX_FRAMES: usize = 200;
+
+    let (_, platforms) = cycle::hashing(input.clone(), |platform| do_spin_cycle(platform.clone()));
+    platforms
+        .iter()
+        .take(MAX_FRAMES)
+        .map(draw_platform)
+        .collect()
+}
+
+/// the platform after any number of spin cycles, the spins going in circles are only done once
+struct Spins {
+    cycle: Cycle,
+    platforms: Vec<Grid<char>>,
+}
+
+impl Spins {
+    fn new(input: &Grid<char>) -> Spins {
+        let (cycle, platforms) =
+            cycle::hashing(input.clone(), |platform| do_spin_cycle(platform.clone()));
+        Spins { cycle, platforms }
+    }
+}
+
+impl Stepper for Spins {
+    fn last_step(&self) -> Option<usize> {
+        None
+    }
+
+    fn frame(&mut self, step: usize) -> Frame {
+        let same_as = self.cycle.reduce(step);
+        let platform = &self.platforms[same_as];
+        Frame {
+            view: platform.to_string(),
+            counters: vec![
+                ("load", calculate_load(platform) as u64),
+                ("same as spin", same_as as u64),
+            ],
+        }
+    }
+}
+
+fn draw_platform(platform: &Grid<char>) -> Image {
+    Image::render(platform, 4, |&rock| match rock {
+        'O' => image::ORANGE,
+        '#' => image::GRAY,
+        _ => image::BLACK,
+    })
+}
+
+fn move_north(platform: &mut Grid<char>, row: usize, col: usize) {
+    for new_row in (0..row).rev() {
+        if platform[(new_row, col)] != '.' {
+            platform[(new_row + 1, col)] = platform[(row, col)];
+
+            if (new_row + 1) != row {
+                platform[(row, col)] = '.';
+            }
+            return;
+        }
+    }
+
+    // if no replacement was done in the loop, rock must end up in row 0
+    platform[(0, col)] = platform[(row, col)];
+    if 0 != row {
+        platform[(row, col)] = '.';
+    }
+}
+
+fn do_spin_cycle(platform: Grid<char>) -> Grid<char> {
+    let mut new_platform = platform;
+
+    for _ in 0..4 {
+        // tilt north
+        for row_idx in 0..new_platform.height() {
+            for col_idx in 0..new_platform.width() {
+                if new_platform[(row_idx, col_idx)] == 'O' {
+                    move_north(&mut new_platform, row_idx, col_idx);
+                }
+            }
+        }
+
+        // why implement tilting west, south and east if you can simply rotate the platform clockwise and tilt north again
+        new_platform = new_platform.rotate_clockwise();
+    }
+
+    new_platform
+}
+
+fn calculate_load(platform: &Grid<char>) -> usize {
+    platform
+        .rows()
+        .rev()
+        .enumerate()
+        .map(|(load, row)| {
+            row.iter()
+                .map(|rock| match rock {
+                    'O' => load + 1,
+                    _ => 0,
+                })
+                .sum::<usize>()
+        })
+        .sum::<usize>()
+}
+
+/// round and cube rocks scattered over the platform
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let side = size * 2;
+    Grid::from_fn(side, side, |_| match rng.gen_range(0..10) {
+        0 | 1 => 'O',
+        2 => '#',
+        _ => '.',
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day14.parse(EXAMPLE).unwrap();
+        assert_eq!(Day14.part_one(&input).unwrap(), Answer::Unsigned(136));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day14.parse(EXAMPLE).unwrap();
+        assert_eq!(Day14.part_two(&input).unwrap(), Answer::Unsigned(64));
+    }
+
+    #[test]
+    fn frames_until_the_spins_repeat() {
+        let input = Day14.parse(EXAMPLE).unwrap();
+        let frames = spin_frames(&input);
+        // the example repeats after 3 spins with a period of 7
+        assert_eq!(frames.len(), 10);
+        assert_eq!(frames[0], draw_platform(&input));
+    }
+
+    #[test]
+    fn steps_through_spins() {
+        let input = Day14.parse(EXAMPLE).unwrap();
+        let mut spins = Day14.stepper(&input).unwrap();
+        assert_eq!(spins.frame(0).view, EXAMPLE);
+        assert_eq!(spins.frame(1000000000).counters[0], ("load", 64));
+        assert_eq!(spins.frame(10).counters[1], ("same as spin", 3));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::util::grid::Grid;
+
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+/// moves the round rocks one tile at a time towards `offset` until none of them can move
+fn tilt(platform: &mut Grid<char>, offset: (isize, isize)) {
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for position in platform.positions().collect::<Vec<_>>() {
+            let Some(next) = platform.offset(position, offset) else {
+                continue;
+            };
+            if platform[position] == 'O' && platform[next] == '.' {
+                platform[next] = 'O';
+                platform[position] = '.';
+                moved = true;
+            }
+        }
+    }
+}
+
+fn load(platform: &Grid<char>) -> usize {
+    platform
+        .iter()
+        .filter(|(_, &rock)| rock == 'O')
+        .map(|((row, _), _)| platform.height() - row)
+        .sum()
+}
+
+pub fn part_one(platform: &Grid<char>) -> usize {
+    let mut platform = platform.clone();
+    tilt(&mut platform, (-1, 0));
+    load(&platform)
+}
+
+/// spins until the platform looks like after an earlier spin cycle, from there on it repeats the same cycles
+pub fn part_two(platform: &Grid<char>) -> usize {
+    let mut platforms = vec![platform.clone()];
+    loop {
+        let mut platform = platforms[platforms.len() - 1].clone();
+        for offset in [(-1, 0), (0, -1), (1, 0), (0, 1)] {
+            tilt(&mut platform, offset);
+        }
+        if let Some(first) = platforms.iter().position(|earlier| *earlier == platform) {
+            let period = platforms.len() - first;
+            return load(&platforms[first + (SPIN_CYCLES - first) % period]);
+        }
+        platforms.push(platform);
+    }
+}
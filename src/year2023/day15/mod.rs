@@ -0,0 +1,161 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::generate;
+use crate::{Answer, AocError, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input = Vec<String>;
+
+    const PART_ONE: &'static str = "Sum of hashes is {}";
+    const PART_TWO: &'static str = "Sum of focusing power is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.join(",") + "\n")
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<String>, AocError> {
+    // any text can be hashed, the steps are only checked by part 2
+    // only the line ends with a newline, a step in the middle can't lose one
+    Ok(input_str
+        .strip_suffix("\n")
+        .unwrap_or(input_str)
+        .split(",")
+        .map(|step| step.to_string())
+        .collect())
+}
+
+fn part_one(input: &Vec<String>) -> u32 {
+    input.into_iter().map(|step| hash(&step)).sum()
+}
+
+fn part_two(input: &Vec<String>) -> Result<usize, AocError> {
+    let mut hash_table: Vec<Vec<(String, u32)>> = (0..256).map(|_| vec![]).collect();
+
+    for lens in input {
+        let lens_bytes = lens.as_bytes();
+        let op_idx = lens
+            .find(['-', '='])
+            .ok_or_else(|| AocError::solve(15, format!("step {lens:?} has no operation")))?;
+        let hash = hash(&lens[..op_idx]) as usize;
+
+        match lens_bytes[op_idx] as char {
+            '-' => {
+                hash_table[hash] = hash_table[hash]
+                    .iter()
+                    .filter(|entry| entry.0 != &lens[..op_idx])
+                    .map(|entry| (entry.0.to_string(), entry.1))
+                    .collect()
+            }
+            '=' => {
+                let focal_length: u32 = lens[op_idx + 1..].parse().map_err(|_| {
+                    AocError::solve(15, format!("step {lens:?} has no focal length"))
+                })?;
+                match hash_table[hash]
+                    .iter()
+                    .any(|entry| entry.0 == &lens[..op_idx])
+                {
+                    true => {
+                        hash_table[hash] = hash_table[hash]
+                            .iter()
+                            .map(|entry| {
+                                if entry.0 == &lens[..op_idx] {
+                                    (entry.0.to_string(), focal_length)
+                                } else {
+                                    (entry.0.to_string(), entry.1)
+                                }
+                            })
+                            .collect()
+                    }
+                    false => hash_table[hash].push((lens[..op_idx].to_string(), focal_length)),
+                }
+            }
+            _ => unreachable!(), // op_idx was found by looking for '-' or '='
+        }
+    }
+
+    let mut focusing_sum = 0;
+
+    for (hash, lens_box) in hash_table.iter().enumerate() {
+        for (pos, lens) in lens_box.iter().enumerate() {
+            focusing_sum += (hash + 1) * (pos + 1) * (lens.1 as usize);
+        }
+    }
+
+    Ok(focusing_sum)
+}
+
+fn hash(step: &str) -> u32 {
+    let mut hash = 0;
+
+    for c in step.chars() {
+        hash = ((hash + c as u32) * 17) % 256;
+    }
+
+    hash
+}
+
+/// steps on just a few labels, so lenses get replaced and removed again
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let labels = generate::distinct_words(rng, generate::LOWERCASE, 3, size, &[]);
+    let steps: Vec<String> = (0..size * 10)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day15.parse(EXAMPLE).unwrap();
+        assert_eq!(Day15.part_one(&input).unwrap(), Answer::Unsigned(1320));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day15.parse(EXAMPLE).unwrap();
+        assert_eq!(Day15.part_two(&input).unwrap(), Answer::Unsigned(145));
+    }
+}
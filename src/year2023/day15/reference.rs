@@ -0,0 +1,37 @@
+fn hash(text: &str) -> usize {
+    text.chars()
+        .fold(0, |value, c| (value + c as usize) * 17 % 256)
+}
+
+pub fn part_one(steps: &[String]) -> usize {
+    steps.iter().map(|step| hash(step)).sum()
+}
+
+pub fn part_two(steps: &[String]) -> usize {
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![vec![]; 256];
+    for step in steps {
+        if let Some(label) = step.strip_suffix('-') {
+            boxes[hash(label)].retain(|(other, _)| *other != label);
+            continue;
+        }
+
+        let (label, focal_length) = step.split_once('=').expect("every step is - or =");
+        let focal_length = focal_length.parse().expect("focal lengths are digits");
+        let lenses = &mut boxes[hash(label)];
+        match lenses.iter_mut().find(|(other, _)| *other == label) {
+            Some(lens) => lens.1 = focal_length,
+            None => lenses.push((label, focal_length)),
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(box_nr, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(slot, (_, focal_length))| (box_nr + 1) * (slot + 1) * focal_length)
+        })
+        .sum()
+}
@@ -0,0 +1,376 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::geometry::Direction;
+use crate::util::grid::Grid;
+use crate::util::image::{self, Image};
+use crate::{Answer, AocError, Artifact, Frame, ParseContext, Solver, Stepper};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Clone, Copy)]
+enum Element {
+    Empty,
+    MirrorForward,
+    MirrorBackward,
+    SplitterHorizontal,
+    SplitterVertical,
+}
+
+impl Element {
+    /// where a beam entering the tile going `direction` goes on to, splitters split it in two
+    fn deflect(self, direction: Direction) -> (Direction, Option<Direction>) {
+        match self {
+            Element::Empty => (direction, None),
+            Element::MirrorForward if direction.is_horizontal() => (direction.turn_left(), None),
+            Element::MirrorForward => (direction.turn_right(), None),
+            Element::MirrorBackward if direction.is_horizontal() => (direction.turn_right(), None),
+            Element::MirrorBackward => (direction.turn_left(), None),
+            Element::SplitterHorizontal if !direction.is_horizontal() => {
+                (Direction::Right, Some(Direction::Left))
+            }
+            Element::SplitterVertical if direction.is_horizontal() => {
+                (Direction::Up, Some(Direction::Down))
+            }
+            // splitters passed on their pointy end act like empty space
+            Element::SplitterHorizontal | Element::SplitterVertical => (direction, None),
+        }
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            Element::Empty => '.',
+            Element::MirrorForward => '/',
+            Element::MirrorBackward => '\\',
+            Element::SplitterHorizontal => '-',
+            Element::SplitterVertical => '|',
+        }
+    }
+}
+
+pub struct Tile {
+    element: Element,
+    energized: bool,
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = Grid<Tile>;
+
+    const PART_ONE: &'static str = "There are {} energized tiles";
+    const PART_TWO: &'static str = "Best configuration yields {} energized tiles";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    /// the tiles energized by the beam of part one in yellow, the mirrors and splitters in gray
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        let contraption = energize(input, 0, 0, Direction::Right);
+        let picture = Image::render(&contraption, 4, |tile| {
+            match (tile.energized, tile.element) {
+                (true, _) => image::YELLOW,
+                (false, Element::Empty) => image::BLACK,
+                (false, _) => image::GRAY,
+            }
+        });
+        Ok(vec![picture.artifact("energized")])
+    }
+
+    /// the beam of part one, every step it moves on by a tile
+    fn stepper(&self, input: &Self::Input) -> Option<Box<dyn Stepper>> {
+        Some(Box::new(Beam::new(input)))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Grid<Tile>, AocError> {
+    let ctx = ParseContext::new(16, input_str);
+
+    Grid::parse(&ctx, input_str, "a contraption", |c| {
+        let element = match c {
+            '.' => Element::Empty,
+            '/' => Element::MirrorForward,
+            '\\' => Element::MirrorBackward,
+            '-' => Element::SplitterHorizontal,
+            '|' => Element::SplitterVertical,
+            _ => return Err("unknown tile".to_string()),
+        };
+        Ok(Tile {
+            element,
+            energized: false,
+        })
+    })
+}
+
+fn part_one(contraption: &Grid<Tile>) -> usize {
+    get_energized(contraption, 0, 0, Direction::Right)
+}
+
+fn part_two(contraption: &Grid<Tile>) -> usize {
+    let mut energized_tiles = 0;
+
+    for starting_row in 0..contraption.height() {
+        energized_tiles = energized_tiles.max(get_energized(
+            contraption,
+            starting_row,
+            0,
+            Direction::Right,
+        ));
+        energized_tiles = energized_tiles.max(get_energized(
+            contraption,
+            starting_row,
+            contraption.width() - 1,
+            Direction::Left,
+        ));
+    }
+
+    for starting_col in 0..contraption.width() {
+        energized_tiles =
+            energized_tiles.max(get_energized(contraption, 0, starting_col, Direction::Down));
+        energized_tiles = energized_tiles.max(get_energized(
+            contraption,
+            contraption.height() - 1,
+            starting_col,
+            Direction::Up,
+        ));
+    }
+
+    energized_tiles
+}
+
+fn get_energized(
+    contraption: &Grid<Tile>,
+    starting_row: usize,
+    starting_col: usize,
+    starting_dir: Direction,
+) -> usize {
+    energize(contraption, starting_row, starting_col, starting_dir)
+        .iter()
+        .filter(|(_, tile)| tile.energized)
+        .count()
+}
+
+/// the contraption with the tiles the beam passes marked as energized
+fn energize(
+    input_contraption: &Grid<Tile>,
+    starting_row: usize,
+    starting_col: usize,
+    starting_dir: Direction,
+) -> Grid<Tile> {
+    let mut contraption = input_contraption.map(|tile| Tile {
+        element: tile.element,
+        energized: false,
+    });
+
+    let mut done_steps: HashSet<(usize, usize, Direction)> = HashSet::new();
+    trace_beam(
+        starting_row,
+        starting_col,
+        starting_dir,
+        &mut contraption,
+        &mut done_steps,
+    );
+
+    contraption
+}
+
+fn trace_beam(
+    starting_row: usize,
+    starting_col: usize,
+    starting_dir: Direction,
+    contraption: &mut Grid<Tile>,
+    done_steps: &mut HashSet<(usize, usize, Direction)>,
+) {
+    let mut position = Some((starting_row, starting_col));
+    let mut direction = starting_dir;
+
+    while let Some((row, col)) = position {
+        if !done_steps.insert((row, col, direction)) {
+            // step has already been done, result will not change, do not repeat
+            return;
+        }
+
+        contraption[(row, col)].energized = true;
+
+        match contraption[(row, col)].element.deflect(direction) {
+            (next, None) => direction = next,
+            (first, Some(second)) => {
+                trace_beam(row, col, first, contraption, done_steps);
+                trace_beam(row, col, second, contraption, done_steps);
+                return;
+            }
+        }
+
+        position = contraption.step((row, col), direction);
+    }
+}
+
+/// the beam of part one traced breadth first, so all its ends move on together
+struct Beam {
+    elements: Grid<Element>,
+    /// the tiles the ends of the beam are on after each step, going which way
+    /// ends entering a tile the same way as some earlier end are dropped, what follows has been seen
+    ends: Vec<Vec<((usize, usize), Direction)>>,
+    /// step at which each tile is first energized
+    energized_at: Grid<Option<usize>>,
+}
+
+impl Beam {
+    fn new(contraption: &Grid<Tile>) -> Beam {
+        let elements = contraption.map(|tile| tile.element);
+        let mut energized_at = contraption.map(|_| None);
+        let mut ends = vec![];
+        let mut seen = HashSet::new();
+        let mut current = vec![((0, 0), Direction::Right)];
+        seen.insert(current[0]);
+
+        while !current.is_empty() {
+            let step = ends.len();
+            let mut next = vec![];
+            for &(position, direction) in &current {
+                energized_at[position].get_or_insert(step);
+                let (first, second) = elements[position].deflect(direction);
+                for direction in [Some(first), second].into_iter().flatten() {
+                    if let Some(ahead) = elements.step(position, direction) {
+                        if seen.insert((ahead, direction)) {
+                            next.push((ahead, direction));
+                        }
+                    }
+                }
+            }
+            ends.push(current);
+            current = next;
+        }
+
+        Beam {
+            elements,
+            ends,
+            energized_at,
+        }
+    }
+}
+
+impl Stepper for Beam {
+    fn last_step(&self) -> Option<usize> {
+        Some(self.ends.len() - 1)
+    }
+
+    /// the ends of the beam as arrows, energized empty tiles as #
+    fn frame(&mut self, step: usize) -> Frame {
+        let mut view =
+            Grid::from_fn(
+                self.elements.width(),
+                self.elements.height(),
+                |position| match (self.elements[position], self.energized_at[position]) {
+                    (Element::Empty, Some(energized)) if energized <= step => '#',
+                    (element, _) => element.symbol(),
+                },
+            );
+        for &(position, direction) in &self.ends[step] {
+            view[position] = match direction {
+                Direction::Right => '>',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+                Direction::Up => '^',
+            };
+        }
+
+        let energized = self
+            .energized_at
+            .iter()
+            .filter(|(_, energized)| energized.is_some_and(|energized| energized <= step))
+            .count();
+        Frame {
+            view: view.to_string(),
+            counters: vec![
+                ("energized tiles", energized as u64),
+                ("beam ends", self.ends[step].len() as u64),
+            ],
+        }
+    }
+}
+
+fn serialize_input(contraption: &Grid<Tile>) -> String {
+    contraption.render(|tile| tile.element.symbol())
+}
+
+/// mostly empty space with some mirrors and splitters
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let side = size * 2;
+    Grid::from_fn(side, side, |_| match rng.gen_range(0..20) {
+        0 => '/',
+        1 => '\\',
+        2 => '-',
+        3 => '|',
+        _ => '.',
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day16.parse(EXAMPLE).unwrap();
+        assert_eq!(Day16.part_one(&input).unwrap(), Answer::Unsigned(46));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day16.parse(EXAMPLE).unwrap();
+        assert_eq!(Day16.part_two(&input).unwrap(), Answer::Unsigned(51));
+    }
+
+    #[test]
+    fn beam_moves_a_tile_per_step() {
+        let input = Day16.parse(EXAMPLE).unwrap();
+        let mut beam = Day16.stepper(&input).unwrap();
+        let start = beam.frame(0);
+        assert!(start.view.starts_with(">|..."));
+        assert_eq!(
+            start.counters,
+            vec![("energized tiles", 1), ("beam ends", 1)]
+        );
+
+        // the beam enters the splitter on the second tile, which sends it down
+        assert!(beam.frame(1).view.starts_with("#>..."));
+        assert!(beam.frame(2).view.starts_with("#|...\\....\n|v"));
+        let last = beam.frame(beam.last_step().unwrap());
+        assert_eq!(
+            last.counters,
+            vec![("energized tiles", 46), ("beam ends", 1)]
+        );
+    }
+}
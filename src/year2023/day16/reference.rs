@@ -0,0 +1,55 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{Element, Tile};
+use crate::util::grid::Grid;
+
+/// where a beam going (d_row, d_col) goes on after passing an element
+fn leaving(element: Element, (d_row, d_col): (isize, isize)) -> Vec<(isize, isize)> {
+    match element {
+        Element::Empty => vec![(d_row, d_col)],
+        // `/` turns a beam going right upwards
+        Element::MirrorForward => vec![(-d_col, -d_row)],
+        // `\` turns a beam going right downwards
+        Element::MirrorBackward => vec![(d_col, d_row)],
+        Element::SplitterHorizontal if d_row != 0 => vec![(0, -1), (0, 1)],
+        Element::SplitterVertical if d_col != 0 => vec![(-1, 0), (1, 0)],
+        Element::SplitterHorizontal | Element::SplitterVertical => vec![(d_row, d_col)],
+    }
+}
+
+/// follows every beam tile by tile until it leaves the contraption or goes where a beam went before
+fn energized(contraption: &Grid<Tile>, start: (usize, usize), heading: (isize, isize)) -> usize {
+    let mut seen = HashSet::new();
+    let mut beams = VecDeque::from([(start, heading)]);
+    while let Some((position, heading)) = beams.pop_front() {
+        if !seen.insert((position, heading)) {
+            continue;
+        }
+        for next_heading in leaving(contraption[position].element, heading) {
+            if let Some(next) = contraption.offset(position, next_heading) {
+                beams.push_back((next, next_heading));
+            }
+        }
+    }
+
+    seen.iter()
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub fn part_one(contraption: &Grid<Tile>) -> usize {
+    energized(contraption, (0, 0), (0, 1))
+}
+
+pub fn part_two(contraption: &Grid<Tile>) -> usize {
+    let (height, width) = (contraption.height(), contraption.width());
+    let from_sides = (0..height).flat_map(|row| [((row, 0), (0, 1)), ((row, width - 1), (0, -1))]);
+    let from_ends = (0..width).flat_map(|col| [((0, col), (1, 0)), ((height - 1, col), (-1, 0))]);
+
+    from_sides
+        .chain(from_ends)
+        .map(|(start, heading)| energized(contraption, start, heading))
+        .max()
+        .unwrap_or_default()
+}
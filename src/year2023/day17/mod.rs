@@ -0,0 +1,187 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::geometry::Direction;
+use crate::util::graph::{self, Graph, Path};
+use crate::util::grid::Grid;
+use crate::util::image::{self, Image};
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Block {
+    row: usize,
+    col: usize,
+    consecutive_steps: u32,
+    /// None at the start, before the first step
+    last_direction: Option<Direction>,
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Grid<u32>;
+
+    const PART_ONE: &'static str = "Heat loss on best route is {}";
+    const PART_TWO: &'static str = "Heat loss of ultra crucible is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(input, false)?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(input, true)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.render(|heat_loss| char::from_digit(*heat_loss, 10).unwrap_or('?')))
+    }
+
+    /// the routes of both crucibles in red over the city, the more heat a block loses the lighter it is
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(vec![
+            draw_route(input, &best_route(input, false)?).artifact("route"),
+            draw_route(input, &best_route(input, true)?).artifact("ultra_route"),
+        ])
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Grid<u32>, AocError> {
+    let ctx = ParseContext::new(17, input_str);
+
+    Grid::parse(&ctx, input_str, "a map of the city", |c| {
+        c.to_digit(10).ok_or("expected a digit".to_string())
+    })
+}
+
+/// nodes are the blocks together with how the crucible got there, because that limits where it can go next
+struct City<'a> {
+    map: &'a Grid<u32>,
+    part_two: bool,
+}
+
+impl Graph for City<'_> {
+    type Node = Block;
+
+    fn neighbors(&self, block: &Block) -> Vec<(Block, u64)> {
+        get_adjacent(*block, self.map, self.part_two)
+            .into_iter()
+            .map(|adjacent| (adjacent, self.map[(adjacent.row, adjacent.col)] as u64))
+            .collect()
+    }
+}
+
+fn part_one_two(city_map: &Grid<u32>, part_two: bool) -> Result<u64, AocError> {
+    Ok(best_route(city_map, part_two)?.cost)
+}
+
+fn best_route(city_map: &Grid<u32>, part_two: bool) -> Result<Path<Block>, AocError> {
+    let starting_block = Block {
+        row: 0,
+        col: 0,
+        consecutive_steps: 0,
+        last_direction: None,
+    };
+    let city = City {
+        map: city_map,
+        part_two,
+    };
+
+    graph::shortest_path(&city, starting_block, |block| {
+        block.row == city_map.height() - 1
+            && block.col == city_map.width() - 1
+            && (!part_two || block.consecutive_steps >= 4)
+    })
+    .ok_or_else(|| AocError::solve(17, "there is no route to the factory"))
+}
+
+fn draw_route(city_map: &Grid<u32>, route: &Path<Block>) -> Image {
+    let mut colors = city_map.map(|&heat_loss| {
+        let shade = (heat_loss.min(9) * 20) as u8 + 20;
+        [shade, shade, shade]
+    });
+    for block in &route.nodes {
+        colors[(block.row, block.col)] = image::RED;
+    }
+    Image::render(&colors, 4, |&color| color)
+}
+
+fn get_adjacent(block: Block, city_map: &Grid<u32>, part_two: bool) -> Vec<Block> {
+    // the ultra crucible needs 4 steps before it can turn and can go up to 10 steps straight
+    let (min_steps, max_steps) = if part_two { (4, 10) } else { (1, 3) };
+    let mut adjacent_blocks = vec![];
+
+    for direction in Direction::ALL {
+        let consecutive_steps = match block.last_direction {
+            Some(last) if direction == last.reverse() => continue,
+            Some(last) if direction == last => block.consecutive_steps + 1,
+            Some(_) if block.consecutive_steps < min_steps => continue,
+            _ => 1,
+        };
+        if consecutive_steps > max_steps {
+            continue;
+        }
+
+        if let Some((row, col)) = city_map.step((block.row, block.col), direction) {
+            adjacent_blocks.push(Block {
+                row,
+                col,
+                consecutive_steps,
+                last_direction: Some(direction),
+            });
+        }
+    }
+
+    adjacent_blocks
+}
+
+/// heat losses from 1 to 9, at least five blocks each way so the ultra crucible can get to the factory
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let width = rng.gen_range(5..=size + 5);
+    let height = rng.gen_range(5..=size + 5);
+    Grid::from_fn(width, height, |_| (b'0' + rng.gen_range(1..=9)) as char).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("example_input_part_two.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day17.parse(EXAMPLE).unwrap();
+        assert_eq!(Day17.part_one(&input).unwrap(), Answer::Unsigned(102));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day17.parse(EXAMPLE).unwrap();
+        assert_eq!(Day17.part_two(&input).unwrap(), Answer::Unsigned(94));
+    }
+
+    #[test]
+    fn part_two_minimum_steps() {
+        let input = Day17.parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(Day17.part_two(&input).unwrap(), Answer::Unsigned(71));
+    }
+}
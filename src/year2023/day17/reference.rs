@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use crate::util::grid::Grid;
+
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// (position, index into DIRECTIONS of the last move, blocks moved in that direction in a row)
+type State = ((usize, usize), usize, usize);
+
+/// lowers the heat loss of every state reachable with one more move from another state, until nothing changes
+/// that's Bellman-Ford, no priority queue that could pop states in the wrong order
+fn least_heat_loss(city: &Grid<u32>, min_straight: usize, max_straight: usize) -> Option<u64> {
+    // the first move can go anywhere
+    let mut best: HashMap<State, u64> = HashMap::new();
+    for (direction, &offset) in DIRECTIONS.iter().enumerate() {
+        if let Some(next) = city.offset((0, 0), offset) {
+            best.insert((next, direction, 1), city[next] as u64);
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for ((position, direction, straight), heat_loss) in best.clone() {
+            for (next_direction, &offset) in DIRECTIONS.iter().enumerate() {
+                let next_straight = if next_direction == direction {
+                    straight + 1
+                } else if next_direction != (direction + 2) % 4 && straight >= min_straight {
+                    1
+                } else {
+                    continue;
+                };
+                let Some(next) = city.offset(position, offset) else {
+                    continue;
+                };
+                if next_straight > max_straight {
+                    continue;
+                }
+
+                let next_heat_loss = heat_loss + city[next] as u64;
+                let state = (next, next_direction, next_straight);
+                if best.get(&state).is_none_or(|&known| next_heat_loss < known) {
+                    best.insert(state, next_heat_loss);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    let end = (city.height() - 1, city.width() - 1);
+    best.into_iter()
+        .filter(|&((position, _, straight), _)| position == end && straight >= min_straight)
+        .map(|(_, heat_loss)| heat_loss)
+        .min()
+}
+
+pub fn part_one(city: &Grid<u32>) -> u64 {
+    least_heat_loss(city, 1, 3).expect("the end can be reached")
+}
+
+pub fn part_two(city: &Grid<u32>) -> u64 {
+    least_heat_loss(city, 4, 10).expect("the end can be reached")
+}
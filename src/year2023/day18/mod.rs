@@ -0,0 +1,392 @@
+use std::collections::VecDeque;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::geometry::{Direction, Point};
+use crate::util::grid::Grid;
+use crate::util::svg::Svg;
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Step {
+    direction: Direction,
+    distance: i32,
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = (Vec<Step>, Vec<Step>);
+
+    const PART_ONE: &'static str = "Lagoon can hold {} cubic meters of lava";
+    const PART_TWO: &'static str = "Bigger lagoon can hold {} cubic meters of lava";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok((parse_input(input)?, parse_real_input(input)?))
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(&input.0).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one_two(&input.1).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(&input.0, &input.1))
+    }
+
+    /// the first dig plan as a map unless it's too big to draw, and both dig plans as polygons
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        let mut artifacts = vec![];
+        if let Some(map) = lagoon_map(&input.0) {
+            artifacts.push(Artifact::text("lagoon.txt", map.to_string()));
+        }
+        artifacts.push(lagoon_polygon(&input.0).artifact("lagoon.svg"));
+        artifacts.push(lagoon_polygon(&input.1).artifact("big_lagoon.svg"));
+        Ok(artifacts)
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(&input.0).into(),
+            _ => reference::part_two(&input.1).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<Step>, AocError> {
+    let ctx = ParseContext::new(18, input_str);
+    let mut steps = vec![];
+
+    for line in input_str.lines() {
+        let (dir_str, rest) = ctx.split_once(line, " ")?;
+        let (dist_str, _) = ctx.split_once(rest, " ")?;
+
+        let distance: i32 = ctx.number(dist_str)?;
+        if distance < 0 {
+            return Err(ctx.error(dist_str, "distance can't be negative"));
+        }
+
+        let direction = dir_str
+            .parse()
+            .ok()
+            .and_then(Direction::from_letter)
+            .ok_or_else(|| ctx.error(dir_str, "expected R, D, L or U"))?;
+
+        steps.push(Step {
+            direction,
+            distance,
+        });
+    }
+
+    Ok(steps)
+}
+
+fn parse_real_input(input_str: &str) -> Result<Vec<Step>, AocError> {
+    let ctx = ParseContext::new(18, input_str);
+    let mut steps = vec![];
+
+    for line in input_str.lines() {
+        let (_, input_str) = ctx.split_once(line, "(#")?;
+        let (actual_input_str, _) = ctx.split_once(input_str, ")")?;
+
+        if actual_input_str.len() != 6 || !actual_input_str.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ctx.error(actual_input_str, "expected a color of six hex digits"));
+        }
+
+        // five hex digits always fit
+        let distance: i32 = i32::from_str_radix(&actual_input_str[0..5], 16).unwrap_or_default();
+
+        let direction = actual_input_str[5..]
+            .parse()
+            .ok()
+            .and_then(Direction::from_digit)
+            .ok_or_else(|| ctx.error(&actual_input_str[5..], "expected a direction from 0 to 3"))?;
+
+        steps.push(Step {
+            direction,
+            distance,
+        });
+    }
+
+    Ok(steps)
+}
+
+fn part_one_two(steps: &[Step]) -> i64 {
+    let (area, boundary_points) = area_and_boundary(steps);
+
+    // solve Pick's theorem for grid points inside
+    boundary_points + (area + 1 - (boundary_points / 2))
+}
+
+/// area enclosed by the middle of the trench, and the number of grid points on the trench
+fn area_and_boundary(steps: &[Step]) -> (i64, i64) {
+    let mut current = Point::ORIGIN;
+
+    let mut double_area: i64 = 0;
+    let mut boundary_points: i64 = 0;
+
+    // shoelace formula to calculate area
+    for step in steps {
+        let next = current.step(step.direction, step.distance as i64);
+
+        double_area += current.x * next.y;
+        double_area -= current.y * next.x;
+
+        current = next;
+
+        boundary_points += step.distance as i64;
+    }
+
+    ((double_area / 2).abs(), boundary_points)
+}
+
+/// corners of the trench, starting and ending at the origin
+fn corners(steps: &[Step]) -> Vec<Point> {
+    let mut corners = vec![Point::ORIGIN];
+    for step in steps {
+        corners.push(corners[corners.len() - 1].step(step.direction, step.distance as i64));
+    }
+    corners
+}
+
+/// smallest and largest x and y of the corners, which always include the origin
+fn bounds(corners: &[Point]) -> (Point, Point) {
+    corners
+        .iter()
+        .fold((Point::ORIGIN, Point::ORIGIN), |(min, max), corner| {
+            (
+                Point::new(min.x.min(corner.x), min.y.min(corner.y)),
+                Point::new(max.x.max(corner.x), max.y.max(corner.y)),
+            )
+        })
+}
+
+/// the middle of the trench as a polygon, with the numbers that go into the lagoon's size
+fn lagoon_polygon(steps: &[Step]) -> Svg {
+    let corners = corners(steps);
+    let (min, max) = bounds(&corners);
+    let mut svg = Svg::new((min.x as f64, min.y as f64), (max.x as f64, max.y as f64));
+    let points: Vec<(f64, f64)> = corners
+        .iter()
+        .map(|corner| (corner.x as f64, corner.y as f64))
+        .collect();
+    svg.polygon(
+        &points,
+        "fill:#8b5a2b;fill-opacity:0.5;stroke:#3b2510;stroke-width:1.5",
+    );
+    svg.dot((0.0, 0.0), 4.0, "fill:red");
+
+    let (area, boundary_points) = area_and_boundary(steps);
+    let (left, top) = svg.top_left();
+    svg.text(
+        (left + svg.pixels(10.0), top + svg.pixels(24.0)),
+        16.0,
+        &format!(
+            "area {area}, {boundary_points} boundary points, lagoon {} cubic meters",
+            part_one_two(steps)
+        ),
+    );
+    svg
+}
+
+/// the trench drawn with pipes like on day 10, everything it encloses as '#',
+/// or none if the map would be too big to draw
+fn lagoon_map(steps: &[Step]) -> Option<Grid<char>> {
+    // the part one input is a few hundred tiles across, dig plans far bigger than that
+    // are only readable as a polygon
+    const MAX_TILES: i64 = 1_000_000;
+
+    let corners = corners(steps);
+    let (min, max) = bounds(&corners);
+    let (min_x, max_x, min_y, max_y) = (min.x, max.x, min.y, max.y);
+
+    // one empty tile around the lagoon so the outside is connected
+    let (width, height) = (max_x - min_x + 3, max_y - min_y + 3);
+    if width.saturating_mul(height) > MAX_TILES {
+        return None;
+    }
+    let (width, height) = (width as usize, height as usize);
+    let position = |point: Point| {
+        (
+            (point.y - min_y + 1) as usize,
+            (point.x - min_x + 1) as usize,
+        )
+    };
+
+    // directions the trench leaves every tile in
+    let mut trench: Grid<Vec<Direction>> = Grid::filled(width, height, vec![]);
+    let mut current = Point::ORIGIN;
+    for step in steps {
+        for _ in 0..step.distance {
+            let next = current + step.direction.delta();
+            trench[position(current)].push(step.direction);
+            trench[position(next)].push(step.direction.reverse());
+            current = next;
+        }
+    }
+
+    let mut outside = trench.map(|_| false);
+    outside[(0, 0)] = true;
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some(tile) = queue.pop_front() {
+        for next in trench.neighbors4(tile) {
+            if trench[next].is_empty() && !outside[next] {
+                outside[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Some(Grid::from_fn(width, height, |tile| {
+        let mut directions = trench[tile].clone();
+        directions.sort();
+        match directions[..] {
+            [] if outside[tile] => '.',
+            [Direction::Right, Direction::Down] => 'F',
+            [Direction::Right, Direction::Left] => '-',
+            [Direction::Right, Direction::Up] => 'L',
+            [Direction::Down, Direction::Left] => '7',
+            [Direction::Down, Direction::Up] => '|',
+            [Direction::Left, Direction::Up] => 'J',
+            _ => '#',
+        }
+    }))
+}
+
+/// both dig plans go into the same lines, the second one as colors
+fn serialize_input(steps: &[Step], real_steps: &[Step]) -> String {
+    steps
+        .iter()
+        .zip(real_steps)
+        .map(|(step, real_step)| {
+            format!(
+                "{} {} (#{:05x}{})\n",
+                letter(step.direction),
+                step.distance,
+                real_step.distance,
+                digit(real_step.direction)
+            )
+        })
+        .collect()
+}
+
+/// inverse of `Direction::from_letter`
+fn letter(direction: Direction) -> char {
+    "RDLU".as_bytes()[digit(direction)] as char
+}
+
+/// inverse of `Direction::from_digit`
+fn digit(direction: Direction) -> usize {
+    Direction::ALL
+        .iter()
+        .position(|&other| other == direction)
+        .unwrap_or_default()
+}
+
+/// dig plans for two histogram shaped lagoons with the same number of steps, one of them in the colors
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let dig = histogram(rng, size, 10);
+    // all columns together have to fit into five hex digits
+    let real_dig = histogram(rng, size, 0xfffff / size as i32);
+
+    serialize_input(&dig, &real_dig)
+}
+
+/// steps around `columns` columns of random heights and widths up to `max`, turned randomly
+fn histogram(rng: &mut StdRng, columns: usize, max: i32) -> Vec<Step> {
+    let mut steps = vec![];
+    let mut height = 0;
+    let mut width = 0;
+    for _ in 0..columns {
+        let next_height = loop {
+            let next_height = rng.gen_range(1..=max);
+            if next_height != height {
+                break next_height;
+            }
+        };
+        if next_height > height {
+            steps.push((Direction::Up, next_height - height));
+        } else {
+            steps.push((Direction::Down, height - next_height));
+        }
+        let column_width = rng.gen_range(1..=max);
+        steps.push((Direction::Right, column_width));
+        height = next_height;
+        width += column_width;
+    }
+    steps.push((Direction::Down, height));
+    steps.push((Direction::Left, width));
+
+    let turns = rng.gen_range(0..4);
+    steps
+        .into_iter()
+        .map(|(direction, distance)| Step {
+            direction: (0..turns).fold(direction, |direction, _| direction.turn_right()),
+            distance,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day18.parse(EXAMPLE).unwrap();
+        assert_eq!(Day18.part_one(&input).unwrap(), Answer::Signed(62));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day18.parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Day18.part_two(&input).unwrap(),
+            Answer::Signed(952408144115)
+        );
+    }
+
+    #[test]
+    fn lagoon_map_matches_area() {
+        let (steps, _) = Day18.parse(EXAMPLE).unwrap();
+        let map = lagoon_map(&steps).unwrap();
+        assert_eq!(map.iter().filter(|(_, &tile)| tile != '.').count(), 62);
+        assert_eq!(map.row(1).iter().collect::<String>(), ".F-----7.");
+    }
+
+    #[test]
+    fn huge_lagoons_are_only_drawn_as_polygons() {
+        // the part two plan of the example is over a million tiles across
+        let (_, big_steps) = Day18.parse(EXAMPLE).unwrap();
+        assert!(lagoon_map(&big_steps).is_none());
+
+        let (_, real_steps) = Day18.parse(EXAMPLE).unwrap();
+        let artifacts = Day18.emit(&(big_steps, real_steps)).unwrap();
+        let names: Vec<&str> = artifacts
+            .iter()
+            .map(|artifact| artifact.name.as_str())
+            .collect();
+        assert_eq!(names, ["lagoon.svg", "big_lagoon.svg"]);
+    }
+
+    #[test]
+    fn lagoon_polygon_has_area_and_boundary() {
+        let (steps, _) = Day18.parse(EXAMPLE).unwrap();
+        let drawn = lagoon_polygon(&steps).to_string();
+        assert!(drawn.contains(r#"<polygon points="0,0 6,0 6,5 4,5 4,7"#));
+        assert!(drawn.contains("area 42, 38 boundary points, lagoon 62 cubic meters"));
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::VecDeque;
+
+use super::Step;
+use crate::util::geometry::Point;
+use crate::util::grid::Grid;
+
+/// where the cells start when squeezing the space between `values`: every value gets a cell of its own and
+/// the gap up to the next value becomes a single cell, with an empty cell on both ends
+/// the last entry only marks where the last cell ends
+fn squeeze(mut values: Vec<i64>) -> Vec<i64> {
+    values.sort_unstable();
+    values.dedup();
+
+    let mut starts = vec![values[0] - 1];
+    for (idx, &value) in values.iter().enumerate() {
+        starts.push(value);
+        if values.get(idx + 1).is_none_or(|&next| next > value + 1) {
+            starts.push(value + 1);
+        }
+    }
+    starts.push(values[values.len() - 1] + 2);
+    starts
+}
+
+/// digs the trench on a map squeezed between its corners and floods everything outside of it,
+/// the rest is the lagoon
+fn lagoon_size(steps: &[Step]) -> i64 {
+    let mut corners = vec![Point::ORIGIN];
+    for step in steps {
+        corners.push(corners[corners.len() - 1].step(step.direction, step.distance as i64));
+    }
+
+    let cols = squeeze(corners.iter().map(|corner| corner.x).collect());
+    let rows = squeeze(corners.iter().map(|corner| corner.y).collect());
+    let cell = |corner: &Point| {
+        let row = rows.binary_search(&corner.y).expect("corners start a row");
+        let col = cols
+            .binary_search(&corner.x)
+            .expect("corners start a column");
+        (row, col)
+    };
+
+    let mut trench = Grid::filled(cols.len() - 1, rows.len() - 1, false);
+    for pair in corners.windows(2) {
+        let (from, to) = (cell(&pair[0]), cell(&pair[1]));
+        for row in from.0.min(to.0)..=from.0.max(to.0) {
+            for col in from.1.min(to.1)..=from.1.max(to.1) {
+                trench[(row, col)] = true;
+            }
+        }
+    }
+
+    // the corner cell is outside, the trench never gets there
+    let mut outside = trench.map(|_| false);
+    outside[(0, 0)] = true;
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some(position) = queue.pop_front() {
+        for next in trench.neighbors4(position) {
+            if !trench[next] && !outside[next] {
+                outside[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    outside
+        .iter()
+        .filter(|(_, &outside)| !outside)
+        .map(|((row, col), _)| (rows[row + 1] - rows[row]) * (cols[col + 1] - cols[col]))
+        .sum()
+}
+
+pub fn part_one(steps: &[Step]) -> i64 {
+    lagoon_size(steps)
+}
+
+pub fn part_two(real_steps: &[Step]) -> i64 {
+    lagoon_size(real_steps)
+}
@@ -0,0 +1,480 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::generate;
+use crate::util::dot::Dot;
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Clone, Copy)]
+enum Attribute {
+    X = 0,
+    M = 1,
+    A = 2,
+    S = 3,
+}
+
+impl Attribute {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "x" => Some(Attribute::X),
+            "m" => Some(Attribute::M),
+            "a" => Some(Attribute::A),
+            "s" => Some(Attribute::S),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        ["x", "m", "a", "s"][self as usize]
+    }
+}
+
+pub struct Condition {
+    attribute: Attribute,
+    greater: bool,
+    target: u64,
+    result: String,
+}
+
+impl Condition {
+    /// the condition as written in the input, like "a<2006"
+    fn check(&self) -> String {
+        format!(
+            "{}{}{}",
+            self.attribute.name(),
+            if self.greater { '>' } else { '<' },
+            self.target
+        )
+    }
+}
+
+/// the rules of each workflow by name, and where parts go that match none of them
+type Workflows = HashMap<String, (Vec<Condition>, String)>;
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = (Workflows, Vec<Vec<u64>>);
+
+    const PART_ONE: &'static str = "The sum of accepted part attributes is {}";
+    const PART_TWO: &'static str = "The sum of possible parts that would be accepted is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(&input.0, &input.1).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(&input.0).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(&input.0, &input.1))
+    }
+
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(vec![workflow_graph(&input.0).artifact("workflows.dot")])
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(&input.0, &input.1).into(),
+            _ => reference::part_two(&input.0).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<(Workflows, Vec<Vec<u64>>), AocError> {
+    let ctx = ParseContext::new(19, input_str);
+    let (workflow_str, part_str) = ctx.split_once(input_str, "\n\n")?;
+
+    // workflow name to the workflows it can send parts to, for checking them once everything is parsed
+    let mut references: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut workflows = HashMap::new();
+
+    for line in workflow_str.lines() {
+        let (name, rule_str) = ctx.split_once(line, "{")?;
+        let rule_str = rule_str
+            .strip_suffix("}")
+            .ok_or_else(|| ctx.error(rule_str, "expected '}' at the end of the workflow"))?;
+        if name.is_empty() || name == "A" || name == "R" {
+            return Err(ctx.error(line, "expected a workflow name"));
+        }
+
+        // last rule is the default, everything before it is a condition
+        let mut rules: Vec<&str> = rule_str.split(",").collect();
+        let default = rules.pop().unwrap_or_default();
+        let mut conditions = vec![];
+        for rule in rules {
+            let (check, result) = ctx.split_once(rule, ":")?;
+            let attribute = check
+                .get(..1)
+                .and_then(Attribute::from_name)
+                .ok_or_else(|| ctx.error(check, "expected x, m, a or s"))?;
+            let greater = match check.get(1..2) {
+                Some(">") => true,
+                Some("<") => false,
+                _ => return Err(ctx.error(check, "expected < or >")),
+            };
+            conditions.push(Condition {
+                attribute,
+                greater,
+                target: ctx.number(&check[2..])?,
+                result: result.to_string(),
+            });
+            references.entry(name).or_default().push(result);
+        }
+        references.entry(name).or_default().push(default);
+
+        if workflows
+            .insert(name.to_string(), (conditions, default.to_string()))
+            .is_some()
+        {
+            return Err(ctx.error(name, "workflow is defined twice"));
+        }
+    }
+
+    // every workflow we send parts to must exist, and parts must not be sent around in circles
+    if !workflows.contains_key("in") {
+        return Err(ctx.error(workflow_str, "there is no workflow named in"));
+    }
+    for targets in references.values() {
+        if let Some(target) = targets
+            .iter()
+            .find(|target| **target != "A" && **target != "R" && !workflows.contains_key(**target))
+        {
+            return Err(ctx.error(target, "unknown workflow"));
+        }
+    }
+    let mut finished = vec![];
+    for name in references.keys() {
+        check_cycles(&ctx, name, &references, &mut vec![], &mut finished)?;
+    }
+
+    let parts = part_str
+        .lines()
+        .map(|line| {
+            let ratings = line
+                .strip_prefix("{")
+                .and_then(|line| line.strip_suffix("}"))
+                .ok_or_else(|| ctx.error(line, "expected a part in braces"))?;
+            let ratings: Vec<&str> = ratings.split(",").collect();
+            if ratings.len() != 4 {
+                return Err(ctx.error(line, "expected ratings for x, m, a and s"));
+            }
+            ratings
+                .iter()
+                .zip(["x", "m", "a", "s"])
+                .map(|(rating, attribute)| {
+                    let (name, value) = ctx.split_once(rating, "=")?;
+                    if name != attribute {
+                        return Err(ctx.error(name, format!("expected rating {attribute}")));
+                    }
+                    ctx.number(value)
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((workflows, parts))
+}
+
+/// depth first search through the workflows, `path` holds the workflows leading to `name`
+fn check_cycles<'a>(
+    ctx: &ParseContext,
+    name: &'a str,
+    references: &HashMap<&'a str, Vec<&'a str>>,
+    path: &mut Vec<&'a str>,
+    finished: &mut Vec<&'a str>,
+) -> Result<(), AocError> {
+    if finished.contains(&name) {
+        return Ok(());
+    }
+    if path.contains(&name) {
+        return Err(ctx.error(name, "workflows send parts around in a circle"));
+    }
+
+    path.push(name);
+    for target in references.get(name).into_iter().flatten() {
+        check_cycles(ctx, target, references, path, finished)?;
+    }
+    path.pop();
+    finished.push(name);
+
+    Ok(())
+}
+
+fn part_one(workflows: &Workflows, parts: &[Vec<u64>]) -> u64 {
+    parts
+        .iter()
+        .filter(|part| is_accepted(part, &workflows, "in"))
+        .map(|part| part.iter().sum::<u64>())
+        .sum()
+}
+
+fn is_accepted(part: &[u64], workflows: &Workflows, workflow: &str) -> bool {
+    let conditions = &workflows[workflow].0;
+
+    for condition in conditions {
+        // != is logical xor
+        // xor-ing the lesser condition and whether the condition should be greater will check condition for either operator
+        if (part[condition.attribute as usize] < condition.target) != condition.greater {
+            match &condition.result[..] {
+                "R" => return false,
+                "A" => return true,
+                workflow => return is_accepted(part, workflows, workflow),
+            };
+        }
+    }
+
+    match &workflows[workflow].1[..] {
+        "R" => return false,
+        "A" => return true,
+        workflow => return is_accepted(part, workflows, workflow),
+    };
+}
+
+fn part_two(workflows: &Workflows) -> u64 {
+    let part_ranges: Vec<(u64, u64)> = (0..4).map(|_| (1, 4000)).collect();
+
+    accept_ranges(&part_ranges, workflows, "in")
+}
+
+fn accept_ranges(part_ranges: &[(u64, u64)], workflows: &Workflows, workflow: &str) -> u64 {
+    let conditions = &workflows[workflow].0;
+    let mut ranges = part_ranges.to_vec();
+    let mut sum: u64 = 0;
+    let mut done = false;
+
+    for condition in conditions {
+        // check if any part of the range fulfills the condition
+        if condition.greater && ranges[condition.attribute as usize].1 > condition.target {
+            // if everything is greater, just throw entire part ranges into new workflow
+            if ranges[condition.attribute as usize].0 > condition.target {
+                sum += match &condition.result[..] {
+                    "R" => 0,
+                    "A" => ranges
+                        .iter()
+                        .map(|(min, max)| max - min + 1)
+                        .product::<u64>(),
+                    workflow => accept_ranges(&ranges, workflows, workflow),
+                };
+                done = true;
+                break;
+            }
+
+            // if not everything is greater, split the ranges
+            // keep going for the leftover parts of this one, and check the new one with its new workflow
+            let mut new_ranges = ranges.clone();
+            new_ranges[condition.attribute as usize].0 = new_ranges[condition.attribute as usize]
+                .0
+                .max(condition.target + 1);
+            ranges[condition.attribute as usize].1 = condition.target;
+            sum += match &condition.result[..] {
+                "R" => 0,
+                "A" => new_ranges
+                    .iter()
+                    .map(|(min, max)| max - min + 1)
+                    .product::<u64>(),
+                workflow => accept_ranges(&new_ranges, workflows, workflow),
+            };
+        } else if !condition.greater && ranges[condition.attribute as usize].0 < condition.target {
+            // if everything is smaller, just throw entire part ranges into new workflow
+            if ranges[condition.attribute as usize].1 < condition.target {
+                sum += match &condition.result[..] {
+                    "R" => 0,
+                    "A" => ranges
+                        .iter()
+                        .map(|(min, max)| max - min + 1)
+                        .product::<u64>(),
+                    workflow => accept_ranges(&ranges, workflows, workflow),
+                };
+                done = true;
+                break;
+            }
+
+            // if not everything is smaller, split the ranges
+            // keep going for the leftover parts of this one, and check the new one with its new workflow
+            let mut new_ranges = ranges.clone();
+            new_ranges[condition.attribute as usize].1 = new_ranges[condition.attribute as usize]
+                .1
+                .min(condition.target - 1);
+            ranges[condition.attribute as usize].0 = condition.target;
+            sum += match &condition.result[..] {
+                "R" => 0,
+                "A" => new_ranges
+                    .iter()
+                    .map(|(min, max)| max - min + 1)
+                    .product::<u64>(),
+                workflow => accept_ranges(&new_ranges, workflows, workflow),
+            };
+        }
+    }
+
+    if !done {
+        sum += match &workflows[workflow].1[..] {
+            "R" => 0,
+            "A" => ranges
+                .iter()
+                .map(|(min, max)| max - min + 1)
+                .product::<u64>(),
+            workflow => accept_ranges(&ranges, workflows, workflow),
+        };
+    }
+
+    sum
+}
+
+/// workflows by name, then the parts
+/// where each workflow sends parts, labeled with the conditions, the default way dashed
+/// parts start at "in" and end up accepted or rejected
+fn workflow_graph(workflows: &Workflows) -> Dot {
+    let mut dot = Dot::directed();
+    dot.node_defaults(&[("shape", "box")]);
+    dot.node("in", &[("style", "filled"), ("fillcolor", "lightblue")]);
+    dot.node(
+        "A",
+        &[
+            ("label", "accepted"),
+            ("shape", "doublecircle"),
+            ("style", "filled"),
+            ("fillcolor", "palegreen"),
+        ],
+    );
+    dot.node(
+        "R",
+        &[
+            ("label", "rejected"),
+            ("shape", "doublecircle"),
+            ("style", "filled"),
+            ("fillcolor", "lightcoral"),
+        ],
+    );
+
+    let mut names: Vec<&String> = workflows.keys().collect();
+    names.sort();
+    for name in names {
+        let (conditions, default) = &workflows[name];
+        for condition in conditions {
+            dot.edge(name, &condition.result, &[("label", &condition.check())]);
+        }
+        dot.edge(name, default, &[("style", "dashed")]);
+    }
+    dot
+}
+
+fn serialize_input(workflows: &Workflows, parts: &[Vec<u64>]) -> String {
+    let mut names: Vec<&String> = workflows.keys().collect();
+    names.sort();
+
+    let mut input = String::new();
+    for name in names {
+        let (conditions, default) = &workflows[name];
+        let mut rules: Vec<String> = conditions
+            .iter()
+            .map(|condition| format!("{}:{}", condition.check(), condition.result))
+            .collect();
+        rules.push(default.clone());
+        input += &format!("{name}{{{}}}\n", rules.join(","));
+    }
+
+    input.push('\n');
+    for part in parts {
+        let ratings: Vec<String> = ["x", "m", "a", "s"]
+            .iter()
+            .zip(part)
+            .map(|(attribute, rating)| format!("{attribute}={rating}"))
+            .collect();
+        input += &format!("{{{}}}\n", ratings.join(","));
+    }
+    input
+}
+
+/// workflows that only send parts further down the list, so never in circles, and parts with random ratings
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let mut names = vec!["in".to_string()];
+    names.extend(generate::distinct_words(
+        rng,
+        generate::LOWERCASE,
+        3,
+        size - 1,
+        &[],
+    ));
+
+    let mut input = String::new();
+    for (idx, name) in names.iter().enumerate() {
+        let targets: Vec<&str> = ["A", "R"]
+            .into_iter()
+            .chain(names[idx + 1..].iter().map(String::as_str))
+            .collect();
+        // real workflows always have at least one rule before the default
+        let condition_count = rng.gen_range(1..=3);
+        let mut rules: Vec<String> = (0..condition_count)
+            .map(|_| {
+                let attribute = ["x", "m", "a", "s"][rng.gen_range(0..4)];
+                let comparison = if rng.gen_bool(0.5) { '<' } else { '>' };
+                let target = targets[rng.gen_range(0..targets.len())];
+                format!(
+                    "{attribute}{comparison}{}:{target}",
+                    rng.gen_range(1..=4000)
+                )
+            })
+            .collect();
+        rules.push(targets[rng.gen_range(0..targets.len())].to_string());
+        input += &format!("{name}{{{}}}\n", rules.join(","));
+    }
+
+    input.push('\n');
+    for _ in 0..size * 3 {
+        let ratings: Vec<String> = ["x", "m", "a", "s"]
+            .iter()
+            .map(|attribute| format!("{attribute}={}", rng.gen_range(1..=4000)))
+            .collect();
+        input += &format!("{{{}}}\n", ratings.join(","));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(Day19.part_one(&input).unwrap(), Answer::Unsigned(19114));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day19.parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Day19.part_two(&input).unwrap(),
+            Answer::Unsigned(167409079868000)
+        );
+    }
+
+    #[test]
+    fn workflow_graph_has_conditions() {
+        let input = Day19.parse(EXAMPLE).unwrap();
+        let dot = workflow_graph(&input.0).to_string();
+        assert!(dot.contains(r#""in" -> "px" [label="s<1351"];"#));
+        assert!(dot.contains(r#""in" -> "qqz" [style="dashed"];"#));
+        assert!(dot.contains(r#""A" [label="accepted""#));
+    }
+}
@@ -0,0 +1,77 @@
+use super::{Condition, Workflows};
+
+/// sends the part through the workflows rule by rule until it's accepted or rejected
+fn accepted(workflows: &Workflows, part: &[u64]) -> bool {
+    let mut workflow = "in";
+    loop {
+        let (conditions, default) = &workflows[workflow];
+        let applies = |condition: &&Condition| {
+            let rating = part[condition.attribute as usize];
+            if condition.greater {
+                rating > condition.target
+            } else {
+                rating < condition.target
+            }
+        };
+        let target = conditions
+            .iter()
+            .find(applies)
+            .map_or(default, |condition| &condition.result);
+
+        match target.as_str() {
+            "A" => return true,
+            "R" => return false,
+            next => workflow = next,
+        }
+    }
+}
+
+pub fn part_one(workflows: &Workflows, parts: &[Vec<u64>]) -> u64 {
+    parts
+        .iter()
+        .filter(|part| accepted(workflows, part))
+        .map(|part| part.iter().sum::<u64>())
+        .sum()
+}
+
+/// there are too many parts to try them all, but between two numbers that the rules compare against every
+/// rule treats all ratings the same, so one part is enough for every box of ratings between those numbers
+pub fn part_two(workflows: &Workflows) -> u64 {
+    // ratings where the result of a rule can change
+    let mut cuts = vec![vec![1, 4001]; 4];
+    for (conditions, _) in workflows.values() {
+        for condition in conditions {
+            // `x>10` is false for 10 and true for 11, `x<10` is true for 9 and false for 10
+            let cut = condition.target + condition.greater as u64;
+            if (1..=4001).contains(&cut) {
+                cuts[condition.attribute as usize].push(cut);
+            }
+        }
+    }
+
+    // (first rating, number of ratings) of every box on each axis
+    let boxes: Vec<Vec<(u64, u64)>> = cuts
+        .iter_mut()
+        .map(|cuts| {
+            cuts.sort_unstable();
+            cuts.dedup();
+            cuts.windows(2)
+                .map(|pair| (pair[0], pair[1] - pair[0]))
+                .collect()
+        })
+        .collect();
+
+    let mut combinations = 0;
+    for x in &boxes[0] {
+        for m in &boxes[1] {
+            for a in &boxes[2] {
+                for s in &boxes[3] {
+                    if accepted(workflows, &[x.0, m.0, a.0, s.0]) {
+                        combinations += x.1 * m.1 * a.1 * s.1;
+                    }
+                }
+            }
+        }
+    }
+    combinations
+}
@@ -0,0 +1,650 @@
+use std::collections::{HashMap, VecDeque};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate;
+use crate::util::cycle::{self, Periodic};
+use crate::util::dot::Dot;
+use crate::{Answer, AocError, Artifact, Frame, ParseContext, Solver, Stepper};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Clone)]
+enum Type {
+    FlipFlop,
+    Conjunction,
+    Broadcaster,
+    Unknown,
+}
+
+impl Type {
+    fn from_prefix(prefix: char) -> Option<Self> {
+        match prefix {
+            '%' => Some(Type::FlipFlop),
+            '&' => Some(Type::Conjunction),
+            'b' => Some(Type::Broadcaster),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Module {
+    module_type: Type,
+    receives_from: HashMap<String, bool>, // last signal received from each input, only remembered for conjunctions
+    sends_to: Vec<String>,
+    is_on: bool, // only relevant for flip flops
+}
+
+struct Pulse {
+    from: String,
+    to: String,
+    is_high: bool,
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = HashMap<String, Module>;
+
+    const PART_ONE: &'static str = "Total high and low pulses multiplied is {}";
+    const PART_TWO: &'static str = "Machine turns on after {} presses";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input.clone())?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input.clone())?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(vec![module_network(input).artifact("modules.dot")])
+    }
+
+    /// one press of the button per step
+    fn stepper(&self, input: &Self::Input) -> Option<Box<dyn Stepper>> {
+        Some(Box::new(Presses::new(input)))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        match part {
+            1 => Some(Ok(reference::part_one(input).into())),
+            _ => reference::part_two(input).map(|presses| Ok(presses.into())),
+        }
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<HashMap<String, Module>, AocError> {
+    let ctx = ParseContext::new(20, input_str);
+    let mut sent_to_list: Vec<(&str, &str)> = vec![];
+    let mut module_map: HashMap<String, Module> = HashMap::new();
+
+    for line in input_str.lines() {
+        let (name, sends_to_list) = ctx.split_once(line, " -> ")?;
+        let module_type = name
+            .chars()
+            .next()
+            .and_then(Type::from_prefix)
+            .ok_or_else(|| ctx.error(name, "expected %, & or broadcaster"))?;
+        let name = match module_type {
+            Type::Broadcaster if name == "broadcaster" => name,
+            Type::Broadcaster => return Err(ctx.error(name, "expected %, & or broadcaster")),
+            _ => &name[1..],
+        };
+        if !is_module_name(name) {
+            return Err(ctx.error(name, "expected a module name"));
+        }
+
+        let mut sends_to = vec![];
+        for to_name in sends_to_list.split(", ") {
+            if !is_module_name(to_name) {
+                return Err(ctx.error(to_name, "expected a module name"));
+            }
+            sent_to_list.push((name, to_name));
+            sends_to.push(to_name.to_string());
+        }
+
+        let module = Module {
+            module_type,
+            receives_from: HashMap::new(),
+            sends_to,
+            is_on: false,
+        };
+        if module_map.insert(name.to_owned(), module).is_some() {
+            return Err(ctx.error(name, "module is defined twice"));
+        }
+    }
+
+    for (from_name, to_name) in sent_to_list {
+        module_map
+            .entry(to_name.to_string())
+            .or_insert(Module {
+                module_type: Type::Unknown,
+                receives_from: HashMap::new(),
+                sends_to: vec![],
+                is_on: false,
+            })
+            .receives_from
+            .insert(from_name.to_owned(), false);
+    }
+
+    Ok(module_map)
+}
+
+fn is_module_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase())
+}
+
+fn part_one(mut module_map: HashMap<String, Module>) -> Result<u64, AocError> {
+    if !module_map.contains_key("broadcaster") {
+        return Err(AocError::solve(20, "there is no broadcaster"));
+    }
+    let mut low_pulses = 0;
+    let mut high_pulses = 0;
+
+    for _ in 0..1000 {
+        press_button(&mut module_map, |pulse| {
+            if pulse.is_high {
+                high_pulses += 1;
+            } else {
+                low_pulses += 1;
+            }
+        });
+    }
+
+    Ok(low_pulses * high_pulses)
+}
+
+fn part_two(mut module_map: HashMap<String, Module>) -> Result<u64, AocError> {
+    let mut button_press: u64 = 0;
+
+    // assumption (that holds true for my input): rx only receives from one module, and that module is a conjunction
+    let feeding_module = module_map
+        .get("rx")
+        .filter(|rx| rx.receives_from.len() == 1)
+        .and_then(|rx| rx.receives_from.keys().next())
+        .map(|name| &module_map[name])
+        .filter(|module| matches!(module.module_type, Type::Conjunction))
+        .ok_or_else(|| AocError::solve(20, "rx needs to be fed by a single conjunction"))?;
+    if !module_map.contains_key("broadcaster") {
+        return Err(AocError::solve(20, "there is no broadcaster"));
+    }
+    // the first two presses at which each input of that conjunction sends a high pulse
+    let mut presses_to_high: HashMap<String, Vec<u64>> = feeding_module
+        .receives_from
+        .keys()
+        .map(|key| (key.to_string(), vec![]))
+        .collect();
+    if presses_to_high.is_empty() {
+        return Err(AocError::solve(
+            20,
+            "the conjunction that feeds rx has no inputs",
+        ));
+    }
+
+    // the press after which each state of the modules was first seen,
+    // once a state comes up again the presses since then repeat forever
+    let mut seen: HashMap<Vec<bool>, u64> = HashMap::from([(memory(&module_map), 0)]);
+
+    // assumption: each input sends high pulses periodically, starting at its first one (which holds true for my input)
+    while presses_to_high.values().any(|presses| presses.len() < 2) {
+        button_press += 1;
+        press_button(&mut module_map, |pulse| {
+            if pulse.is_high {
+                if let Some(presses) = presses_to_high.get_mut(&pulse.from) {
+                    if presses.len() < 2 && presses.last() != Some(&button_press) {
+                        presses.push(button_press);
+                    }
+                }
+            }
+        });
+
+        if let Some(&first_seen) = seen.get(&memory(&module_map)) {
+            // only high pulses in the repeating presses come again, one period later
+            let period = button_press - first_seen;
+            for (name, presses) in presses_to_high.iter_mut() {
+                match presses[..] {
+                    [_, _] => {}
+                    [last] if last > first_seen => presses.push(last + period),
+                    _ => {
+                        return Err(AocError::solve(
+                            20,
+                            format!(
+                            "{name} never sends two high pulses to the conjunction that feeds rx"
+                        ),
+                        ))
+                    }
+                }
+            }
+            break;
+        }
+        seen.insert(memory(&module_map), button_press);
+    }
+
+    let events: Vec<Periodic> = presses_to_high
+        .values()
+        .map(|presses| Periodic {
+            offset: presses[0],
+            period: presses[1] - presses[0],
+        })
+        .collect();
+    cycle::combine(&events)
+        .map(|together| together.offset)
+        .ok_or_else(|| {
+            AocError::solve(
+                20,
+                "the inputs of the conjunction never send high pulses on the same press",
+            )
+        })
+}
+
+/// whether each flip-flop is on and what each conjunction remembers, in the same order for the same modules
+fn memory(module_map: &HashMap<String, Module>) -> Vec<bool> {
+    module_map
+        .values()
+        .flat_map(|module| {
+            let remembered = match module.module_type {
+                Type::Conjunction => module.receives_from.values().copied().collect(),
+                _ => vec![],
+            };
+            std::iter::once(module.is_on).chain(remembered)
+        })
+        .collect()
+}
+
+/// pushes the button once, `on_pulse` sees every pulse sent on the way, starting with the one from the button
+fn press_button(module_map: &mut HashMap<String, Module>, mut on_pulse: impl FnMut(&Pulse)) {
+    let mut pulses = VecDeque::from([Pulse {
+        from: "button".to_string(),
+        to: "broadcaster".to_string(),
+        is_high: false,
+    }]);
+
+    while let Some(pulse) = pulses.pop_front() {
+        on_pulse(&pulse);
+        let Some(module) = module_map.get_mut(&pulse.to) else {
+            continue;
+        };
+        let is_high = match module.module_type {
+            Type::Broadcaster => pulse.is_high,
+            Type::FlipFlop if !pulse.is_high => {
+                module.is_on = !module.is_on;
+                module.is_on
+            }
+            Type::Conjunction => {
+                *module
+                    .receives_from
+                    .get_mut(&pulse.from)
+                    .expect("No module found!") = pulse.is_high;
+                !module.receives_from.values().all(|is_high| *is_high)
+            }
+            Type::FlipFlop | Type::Unknown => continue,
+        };
+        for to_mod in module.sends_to.iter() {
+            pulses.push_back(Pulse {
+                from: pulse.to.clone(),
+                to: to_mod.clone(),
+                is_high,
+            });
+        }
+    }
+}
+
+/// the modules after some number of button presses
+#[derive(Clone)]
+struct Machine {
+    presses: usize,
+    module_map: HashMap<String, Module>,
+    low_pulses: u64,
+    high_pulses: u64,
+    /// low and high pulses each module got during the last press
+    received: HashMap<String, (u64, u64)>,
+}
+
+impl Machine {
+    fn press(&mut self) {
+        self.presses += 1;
+        self.received.clear();
+        let received = &mut self.received;
+        let (mut low_pulses, mut high_pulses) = (0, 0);
+        press_button(&mut self.module_map, |pulse| {
+            let (low, high) = received.entry(pulse.to.clone()).or_default();
+            if pulse.is_high {
+                *high += 1;
+                high_pulses += 1;
+            } else {
+                *low += 1;
+                low_pulses += 1;
+            }
+        });
+        self.low_pulses += low_pulses;
+        self.high_pulses += high_pulses;
+    }
+
+    /// a table with a row per module: what it remembers, where it sends to and the pulses it just got
+    fn draw(&self) -> String {
+        let mut names: Vec<&String> = self.module_map.keys().collect();
+        names.sort();
+
+        let rows: Vec<[String; 4]> = names
+            .into_iter()
+            .map(|name| {
+                let module = &self.module_map[name];
+                let (label, memory) = match module.module_type {
+                    Type::FlipFlop => (
+                        format!("%{name}"),
+                        if module.is_on { "on" } else { "off" }.to_string(),
+                    ),
+                    Type::Conjunction => {
+                        let mut inputs: Vec<String> = module
+                            .receives_from
+                            .iter()
+                            .map(|(from, &is_high)| {
+                                format!("{from}:{}", if is_high { "high" } else { "low" })
+                            })
+                            .collect();
+                        inputs.sort();
+                        (format!("&{name}"), inputs.join(" "))
+                    }
+                    Type::Broadcaster | Type::Unknown => (name.clone(), String::new()),
+                };
+                let sends_to = if module.sends_to.is_empty() {
+                    String::new()
+                } else {
+                    format!("-> {}", module.sends_to.join(", "))
+                };
+                let received = match self.received.get(name) {
+                    Some((low, high)) => format!("got {low} low, {high} high"),
+                    None => String::new(),
+                };
+                [label, memory, sends_to, received]
+            })
+            .collect();
+
+        let mut widths = [0; 4];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        rows.iter()
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(widths)
+                    .map(|(cell, width)| format!("{cell:width$}"))
+                    .collect();
+                format!("{}\n", cells.join("  ").trim_end())
+            })
+            .collect()
+    }
+}
+
+/// the machine after each press of the button, there is no end to pressing it
+/// going back starts over from the last checkpoint, so the thousands of presses needed don't have to be stored
+struct Presses {
+    /// the machine after every `CHECKPOINT` presses
+    checkpoints: Vec<Machine>,
+    current: Machine,
+}
+
+const CHECKPOINT: usize = 100;
+
+impl Presses {
+    fn new(module_map: &HashMap<String, Module>) -> Presses {
+        let start = Machine {
+            presses: 0,
+            module_map: module_map.clone(),
+            low_pulses: 0,
+            high_pulses: 0,
+            received: HashMap::new(),
+        };
+        Presses {
+            checkpoints: vec![start.clone()],
+            current: start,
+        }
+    }
+}
+
+impl Stepper for Presses {
+    fn last_step(&self) -> Option<usize> {
+        None
+    }
+
+    fn frame(&mut self, step: usize) -> Frame {
+        let checkpoint = &self.checkpoints[(step / CHECKPOINT).min(self.checkpoints.len() - 1)];
+        if self.current.presses > step || checkpoint.presses > self.current.presses {
+            self.current = checkpoint.clone();
+        }
+        while self.current.presses < step {
+            self.current.press();
+            if self.current.presses == self.checkpoints.len() * CHECKPOINT {
+                self.checkpoints.push(self.current.clone());
+            }
+        }
+
+        let machine = &self.current;
+        let (pressed_low, pressed_high) = machine
+            .received
+            .values()
+            .fold((0, 0), |(low, high), received| {
+                (low + received.0, high + received.1)
+            });
+        let flip_flops_on = machine
+            .module_map
+            .values()
+            .filter(|module| matches!(module.module_type, Type::FlipFlop) && module.is_on)
+            .count();
+        Frame {
+            view: machine.draw(),
+            counters: vec![
+                ("low pulses", machine.low_pulses),
+                ("high pulses", machine.high_pulses),
+                ("low this press", pressed_low),
+                ("high this press", pressed_high),
+                ("flip-flops on", flip_flops_on as u64),
+            ],
+        }
+    }
+}
+
+/// modules by name, the ones that are only sent to aren't written
+fn serialize_input(module_map: &HashMap<String, Module>) -> String {
+    let mut names: Vec<&String> = module_map.keys().collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let module = &module_map[name];
+            let prefix = match module.module_type {
+                Type::FlipFlop => "%",
+                Type::Conjunction => "&",
+                Type::Broadcaster => "",
+                Type::Unknown => return None,
+            };
+            Some(format!(
+                "{prefix}{name} -> {}\n",
+                module.sends_to.join(", ")
+            ))
+        })
+        .collect()
+}
+
+/// flip-flops as yellow boxes and conjunctions as blue trapezoids, the modules that only receive (like rx) red
+fn module_network(module_map: &HashMap<String, Module>) -> Dot {
+    let mut names: Vec<&String> = module_map.keys().collect();
+    names.sort();
+
+    let mut dot = Dot::directed();
+    dot.node("button", &[("shape", "plaintext")]);
+    dot.edge("button", "broadcaster", &[]);
+    for name in names {
+        let module = &module_map[name];
+        let (label, shape, fill) = match module.module_type {
+            Type::FlipFlop => (format!("%{name}"), "box", "lightyellow"),
+            Type::Conjunction => (format!("&{name}"), "invtrapezium", "lightblue"),
+            Type::Broadcaster => (name.clone(), "box", "palegreen"),
+            Type::Unknown => (name.clone(), "doublecircle", "lightcoral"),
+        };
+        dot.node(
+            name,
+            &[
+                ("label", &label),
+                ("shape", shape),
+                ("style", "filled"),
+                ("fillcolor", fill),
+            ],
+        );
+        for to_name in &module.sends_to {
+            dot.edge(name, to_name, &[]);
+        }
+    }
+    dot
+}
+
+/// binary counters like in the real inputs, each a chain of flip-flops with a conjunction that resets it
+/// once it counts up to some number, and sends a high pulse through an inverter to the conjunction that feeds rx
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let bits = size.clamp(3, 12);
+    let counters = rng.gen_range(2..=4);
+    let names = generate::distinct_words(
+        rng,
+        generate::LOWERCASE,
+        2,
+        1 + counters * (bits + 2),
+        &["rx"],
+    );
+    let feeding = &names[0];
+
+    let mut lines = vec![format!("&{feeding} -> rx\n")];
+    let mut first_flip_flops = vec![];
+    for counter in names[1..].chunks(bits + 2) {
+        let (conjunction, inverter, flip_flops) = (&counter[0], &counter[1], &counter[2..]);
+        // the flip-flops of the set bits send to the conjunction, the others get reset by it
+        // the lowest bit is always set, and is counted past the limit by the reset
+        let limit: usize = rng.gen_range(1 << (bits - 1)..1 << bits) | 1;
+        let mut resets = vec![inverter.as_str()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut sends_to = vec![];
+            if let Some(next) = flip_flops.get(bit + 1) {
+                sends_to.push(next.as_str());
+            }
+            if limit & (1 << bit) != 0 {
+                sends_to.push(conjunction);
+            }
+            if bit == 0 || limit & (1 << bit) == 0 {
+                resets.push(flip_flop);
+            }
+            lines.push(format!("%{flip_flop} -> {}\n", sends_to.join(", ")));
+        }
+        lines.push(format!("&{conjunction} -> {}\n", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {feeding}\n"));
+        first_flip_flops.push(flip_flops[0].as_str());
+    }
+    lines.push(format!("broadcaster -> {}\n", first_flip_flops.join(", ")));
+    lines.shuffle(rng);
+
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+    const EXAMPLE_2: &str = include_str!("example_input_2.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day20.parse(EXAMPLE).unwrap();
+        assert_eq!(Day20.part_one(&input).unwrap(), Answer::Unsigned(32000000));
+    }
+
+    #[test]
+    fn part_one_with_output_module() {
+        let input = Day20.parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day20.part_one(&input).unwrap(), Answer::Unsigned(11687500));
+    }
+
+    #[test]
+    fn module_types_are_styled() {
+        let input = Day20.parse(EXAMPLE_2).unwrap();
+        let dot = module_network(&input).to_string();
+        assert!(dot.contains(r#""a" [label="%a", shape="box""#));
+        assert!(dot.contains(r#""inv" [label="&inv", shape="invtrapezium""#));
+        assert!(dot.contains(r#""output" [label="output", shape="doublecircle""#));
+        assert!(dot.contains(r#""con" -> "output";"#));
+    }
+
+    #[test]
+    fn steps_through_presses() {
+        let input = Day20.parse(EXAMPLE_2).unwrap();
+        let mut presses = Day20.stepper(&input).unwrap();
+        let thousand = presses.frame(1000);
+        assert_eq!(
+            thousand.counters[..2],
+            [("low pulses", 4250), ("high pulses", 2750)]
+        );
+
+        // going back starts over from a checkpoint and has to end up in the same state
+        let first = presses.frame(1);
+        assert_eq!(
+            first.view,
+            "%a           on             -> inv, con  got 1 low, 0 high\n\
+             %b           on             -> con       got 1 low, 0 high\n\
+             broadcaster                 -> a         got 1 low, 0 high\n\
+             &con         a:high b:high  -> output    got 0 low, 2 high\n\
+             &inv         a:high         -> b         got 0 low, 1 high\n\
+             output                                   got 1 low, 1 high\n"
+        );
+        assert_eq!(presses.frame(999), presses.frame(999));
+        assert_eq!(presses.frame(1000), thousand);
+    }
+
+    #[test]
+    fn part_two_stops_when_an_input_never_sends_high() {
+        // g has no inputs, so it never sends a high pulse to f, and the presses go in circles
+        let input = Day20
+            .parse("broadcaster -> a\n%a -> f\n&g -> f\n&f -> rx\n")
+            .unwrap();
+        assert!(matches!(
+            Day20.part_two(&input),
+            Err(AocError::Solve { day: 20, .. })
+        ));
+        let input = Day20
+            .parse("broadcaster -> a\n%a -> b\n&f -> rx\n")
+            .unwrap();
+        assert!(matches!(
+            Day20.part_two(&input),
+            Err(AocError::Solve { day: 20, .. })
+        ));
+    }
+
+    #[test]
+    fn part_two_needs_rx() {
+        // there is no published example for part 2, the examples have no rx module
+        let input = Day20.parse(EXAMPLE_2).unwrap();
+        assert!(matches!(
+            Day20.part_two(&input),
+            Err(AocError::Solve { day: 20, .. })
+        ));
+    }
+}
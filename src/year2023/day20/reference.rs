@@ -0,0 +1,90 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::{Module, Type};
+
+/// the state of all modules, kept apart from the parsed ones so nothing the real solver set up is used
+struct Machine<'a> {
+    modules: &'a HashMap<String, Module>,
+    flip_flops: HashMap<&'a str, bool>,
+    /// last pulse from every input of each conjunction, true for high
+    memory: HashMap<&'a str, HashMap<&'a str, bool>>,
+}
+
+impl<'a> Machine<'a> {
+    fn new(modules: &'a HashMap<String, Module>) -> Self {
+        let mut memory: HashMap<&str, HashMap<&str, bool>> = HashMap::new();
+        for (name, module) in modules {
+            for target in &module.sends_to {
+                if modules
+                    .get(target)
+                    .is_some_and(|target| matches!(target.module_type, Type::Conjunction))
+                {
+                    memory.entry(target).or_default().insert(name, false);
+                }
+            }
+        }
+
+        Machine {
+            modules,
+            flip_flops: HashMap::new(),
+            memory,
+        }
+    }
+
+    /// every pulse sent after pushing the button once, as (from, to, high)
+    fn push_button(&mut self) -> Vec<(&'a str, &'a str, bool)> {
+        let mut sent = vec![];
+        let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((from, to, high)) = queue.pop_front() {
+            sent.push((from, to, high));
+            let Some((to, module)) = self.modules.get_key_value(to) else {
+                continue;
+            };
+
+            let output = match module.module_type {
+                Type::Broadcaster => high,
+                Type::FlipFlop if !high => {
+                    let on = self.flip_flops.entry(to).or_default();
+                    *on = !*on;
+                    *on
+                }
+                Type::Conjunction => {
+                    let memory = self.memory.entry(to).or_default();
+                    memory.insert(from, high);
+                    !memory.values().all(|&high| high)
+                }
+                Type::FlipFlop | Type::Unknown => continue,
+            };
+            for target in &module.sends_to {
+                queue.push_back((to, target, output));
+            }
+        }
+        sent
+    }
+}
+
+pub fn part_one(modules: &HashMap<String, Module>) -> u64 {
+    let mut machine = Machine::new(modules);
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..1000 {
+        for (_, _, is_high) in machine.push_button() {
+            if is_high {
+                high += 1;
+            } else {
+                low += 1;
+            }
+        }
+    }
+    low * high
+}
+
+/// None if that takes more than a million presses, the real machines need trillions
+pub fn part_two(modules: &HashMap<String, Module>) -> Option<u64> {
+    let mut machine = Machine::new(modules);
+    (1..=1_000_000).find(|_| {
+        machine
+            .push_button()
+            .iter()
+            .any(|&(_, to, high)| to == "rx" && !high)
+    })
+}
@@ -0,0 +1,279 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::geometry::{Direction, Point};
+use crate::util::graph::{self, Graph};
+use crate::util::grid::Grid;
+use crate::util::image::{self, Image};
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = (Grid<bool>, (usize, usize));
+
+    const PART_ONE: &'static str = "Can reach {} plots in 64 steps";
+    const PART_TWO: &'static str = "Can reach {} plots in 26501365 steps";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        let (passable, (starting_row, starting_col)) = input;
+        Ok(part_one(passable, *starting_row, *starting_col, 64).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        let (passable, (starting_row, starting_col)) = input;
+        Ok(part_two(passable, *starting_row, *starting_col, 26501365)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(&input.0, input.1))
+    }
+
+    /// the plots reachable after each of the 64 steps of part one, the start in red
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        let (passable, start) = input;
+        Ok(image::frames("steps", step_frames(passable, *start, 64)))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        match part {
+            1 => Some(Ok(reference::part_one(&input.0, input.1).into())),
+            _ => Some(reference::part_two(&input.0, input.1).map(Answer::from)),
+        }
+    }
+}
+
+/// returns bool map of plots (true: passable, false: rock) and tuple of starting coordinates (row, col)
+fn parse_input(input_str: &str) -> Result<(Grid<bool>, (usize, usize)), AocError> {
+    let ctx = ParseContext::new(21, input_str);
+    let map = Grid::parse_chars(&ctx, input_str, ".#S", "a map of the garden")?;
+
+    let mut starts = map.iter().filter(|(_, c)| **c == 'S').map(|(pos, _)| pos);
+    let start = starts
+        .next()
+        .ok_or_else(|| ctx.error(input_str, "expected a starting position"))?;
+    if let Some((row, col)) = starts.next() {
+        let line = input_str.lines().nth(row).unwrap_or_default();
+        return Err(ctx.error(&line[col..], "expected only one starting position"));
+    }
+
+    Ok((map.map(|c| *c != '#'), start))
+}
+
+/// the garden repeats infinitely in every direction, so plots are points instead of grid positions
+struct Garden<'a>(&'a Grid<bool>);
+
+impl Graph for Garden<'_> {
+    type Node = Point;
+
+    fn neighbors(&self, plot: &Point) -> Vec<(Point, u64)> {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| *plot + direction.delta())
+            .filter(|next| *self.0.get_wrapping(next.y, next.x))
+            .map(|next| (next, 1))
+            .collect()
+    }
+}
+
+fn part_one(
+    passable: &Grid<bool>,
+    starting_row: usize,
+    starting_col: usize,
+    step_count: u32,
+) -> u32 {
+    let step_mod = step_count % 2; // anything that's reachable in an even amount of steps is also reachable in a larger even amount by going back and forth, same for uneven
+
+    let start = Point::from_position((starting_row, starting_col));
+    graph::bfs_distances(&Garden(passable), start, Some(step_count as usize))
+        .values()
+        .filter(|&&steps| steps as u32 % 2 == step_mod)
+        .count() as u32
+}
+
+/// one picture for every step count up to `step_count`, only of the map itself, not of its copies
+fn step_frames(passable: &Grid<bool>, start: (usize, usize), step_count: usize) -> Vec<Image> {
+    let distances = graph::bfs_distances(
+        &Garden(passable),
+        Point::from_position(start),
+        Some(step_count),
+    );
+    let mut distance_map = passable.map(|_| None);
+    for (plot, steps) in distances {
+        if let Some((row, col)) = plot.to_position() {
+            if let Some(distance) = distance_map.get_mut(row, col) {
+                *distance = Some(steps);
+            }
+        }
+    }
+
+    (0..=step_count)
+        .map(|steps| {
+            let colors = Grid::from_fn(passable.width(), passable.height(), |position| {
+                match (passable[position], distance_map[position]) {
+                    _ if position == start => image::RED,
+                    (false, _) => image::GRAY,
+                    // going back and forth, a plot stays reachable every other step
+                    (true, Some(distance)) if distance <= steps && distance % 2 == steps % 2 => {
+                        image::GREEN
+                    }
+                    (true, _) => image::BLACK,
+                }
+            });
+            Image::render(&colors, 4, |&color| color)
+        })
+        .collect()
+}
+
+fn part_two(
+    passable: &Grid<bool>,
+    starting_row: usize,
+    starting_col: usize,
+    step_count: u32,
+) -> Result<usize, AocError> {
+    let side = passable.height() as u32;
+    let steps_to_edge = side / 2;
+    if passable.width() as u32 != side
+        || (starting_row as u32, starting_col as u32) != (steps_to_edge, steps_to_edge)
+        || step_count < steps_to_edge
+        || !(step_count - steps_to_edge).is_multiple_of(side)
+    {
+        return Err(AocError::solve(
+            21,
+            "the map needs to be square with the start in the center, and the steps must end on an edge",
+        ));
+    }
+
+    // assumption: step count is n*(input side) + (steps to reach input edge)
+    // I genuinely have no idea how this works, I just looked at some data points and found the quadratic progression
+
+    // the following code just interpolates a quadratic function from three points (with n=0, n=1 and n=2)
+    let c = part_one(passable, starting_row, starting_col, steps_to_edge as u32) as f64;
+
+    let x1: f64 = 1.0;
+    let y1 = part_one(
+        passable,
+        starting_row,
+        starting_col,
+        x1 as u32 * side + steps_to_edge,
+    ) as f64;
+
+    let x2: f64 = 2.0;
+    let y2 = part_one(
+        passable,
+        starting_row,
+        starting_col,
+        x2 as u32 * side + steps_to_edge,
+    ) as f64;
+
+    let a = y1 / ((x1 - x2) * x1) + y2 / ((x2 - x1) * x2) + c / (x1 * x2);
+
+    let b =
+        0.0 - y1 * x2 / ((x1 - x2) * x1) - y2 * x1 / ((x2 - x1) * x2) - c * (x1 + x2) / (x1 * x2);
+
+    let n: usize = ((step_count - steps_to_edge) / side) as usize;
+
+    Ok(a as usize * n * n + b as usize * n + c as usize)
+}
+
+fn serialize_input(passable: &Grid<bool>, start: (usize, usize)) -> String {
+    Grid::from_fn(passable.width(), passable.height(), |position| {
+        match (position == start, passable[position]) {
+            (true, _) => 'S',
+            (false, true) => '.',
+            (false, false) => '#',
+        }
+    })
+    .to_string()
+}
+
+/// rocks everywhere except the row and column of the start
+/// the map has the real size, part 2 only works if 26501365 steps end on the edge of a copy of the map
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    const SIDE: usize = 131;
+    let center = SIDE / 2;
+    let rock_chance = size.min(40) as f64 / 100.0;
+    Grid::from_fn(SIDE, SIDE, |(row, col)| {
+        if (row, col) == (center, center) {
+            'S'
+        } else if row != center && col != center && rng.gen_bool(rock_chance) {
+            '#'
+        } else {
+            '.'
+        }
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    // the example doesn't have the clear row and column through the start that part 2 relies on,
+    // so only the step counting is tested, on the infinitely repeating map for the larger counts
+
+    #[test]
+    fn part_one_example() {
+        let (passable, (row, col)) = Day21.parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&passable, row, col, 6), 16);
+    }
+
+    #[test]
+    fn frames_show_reachable_plots() {
+        let (passable, start) = Day21.parse(EXAMPLE).unwrap();
+        let frames = step_frames(&passable, start, 6);
+        assert_eq!(frames.len(), 7);
+        let green = passable
+            .positions()
+            .filter(|&(row, col)| frames[6].pixel(col * 4, row * 4) == image::GREEN)
+            .count();
+        // the start is reachable as well, but drawn red
+        assert_eq!(green, 15);
+    }
+
+    #[test]
+    fn repeating_map() {
+        let (passable, (row, col)) = Day21.parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&passable, row, col, 10), 50);
+        assert_eq!(part_one(&passable, row, col, 50), 1594);
+        assert_eq!(part_one(&passable, row, col, 100), 6536);
+    }
+
+    #[cfg(feature = "reference")]
+    #[test]
+    fn quadratic_fit_matches_stepping() {
+        // stepping through a map of the real size takes too long, this one has the same shape
+        const SIDE: usize = 11;
+        let mut rng = crate::generate::rng(0);
+        for _ in 0..5 {
+            let center = SIDE / 2;
+            let passable = Grid::from_fn(SIDE, SIDE, |(row, col)| {
+                row == center || col == center || !rng.gen_bool(0.2)
+            });
+            // the fit goes through the counts for n = 0, 1 and 2, so n = 3 is the first real check
+            for n in 3..=5 {
+                let steps = n * SIDE as u32 + center as u32;
+                assert_eq!(
+                    part_two(&passable, center, center, steps).unwrap(),
+                    reference::reachable(&passable, (center, center), steps),
+                    "{n} maps out\n{}",
+                    serialize_input(&passable, (center, center))
+                );
+            }
+        }
+    }
+}
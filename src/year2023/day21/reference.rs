@@ -0,0 +1,68 @@
+use crate::util::grid::Grid;
+use crate::AocError;
+
+/// plots the elf can be on after exactly `steps` steps on the endlessly repeating garden, going one step at a time
+pub fn reachable(passable: &Grid<bool>, start: (usize, usize), steps: u32) -> usize {
+    reachable_after(passable, start, &[steps])[0]
+}
+
+/// `reachable` for several step counts in ascending order, stepping only once up to the largest
+/// the plots are kept in a square around the start that's just big enough, a hash set of them is too slow for part 2
+pub fn reachable_after(passable: &Grid<bool>, start: (usize, usize), steps: &[u32]) -> Vec<usize> {
+    // one more than the furthest the elf gets, so the neighbors of every reachable plot are in the square
+    let center = steps.iter().copied().max().unwrap_or(0) as usize + 1;
+    let side = 2 * center + 1;
+    let tiled = Grid::from_fn(side, side, |(row, col)| {
+        *passable.get_wrapping(
+            (start.0 + row) as i64 - center as i64,
+            (start.1 + col) as i64 - center as i64,
+        )
+    });
+
+    let mut plots = Grid::from_fn(side, side, |position| position == (center, center));
+    // holds the plots of two steps ago, which are all overwritten since they're closer to the start
+    let mut next = Grid::from_fn(side, side, |_| false);
+    let mut counts = vec![];
+    let mut taken = 0;
+    for &step_count in steps {
+        while taken < step_count as usize {
+            taken += 1;
+            // after `taken` steps, nothing is further than `taken` away from the start
+            for row in center - taken..=center + taken {
+                let cols_left = taken - row.abs_diff(center);
+                for col in center - cols_left..=center + cols_left {
+                    next[(row, col)] = tiled[(row, col)]
+                        && (plots[(row - 1, col)]
+                            || plots[(row + 1, col)]
+                            || plots[(row, col - 1)]
+                            || plots[(row, col + 1)]);
+                }
+            }
+            std::mem::swap(&mut plots, &mut next);
+        }
+        counts.push(plots.iter().filter(|(_, &plot)| plot).count());
+    }
+    counts
+}
+
+pub fn part_one(passable: &Grid<bool>, start: (usize, usize)) -> usize {
+    reachable(passable, start, 64)
+}
+
+/// there's no stepping 26501365 steps, so this checks the quadratic fit of part 2 against stepping
+/// 3 to 5 map widths out instead, the fit already goes through the counts for 0 to 2 of them
+pub fn part_two(passable: &Grid<bool>, start: (usize, usize)) -> Result<usize, AocError> {
+    let side = passable.height() as u32;
+    let steps: Vec<u32> = (3..=5).map(|n| n * side + side / 2).collect();
+    let counts = reachable_after(passable, start, &steps);
+    for (steps, count) in steps.into_iter().zip(counts) {
+        let fit = super::part_two(passable, start.0, start.1, steps)?;
+        if fit != count {
+            return Err(AocError::solve(
+                21,
+                format!("part 2: the quadratic fit gives {fit} plots after {steps} steps, but stepping gives {count}"),
+            ));
+        }
+    }
+    super::part_two(passable, start.0, start.1, 26501365)
+}
@@ -0,0 +1,491 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::grid::Grid;
+use crate::util::svg::Svg;
+use crate::{Answer, AocError, Artifact, Frame, ParseContext, Solver, Stepper};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Clone, Copy, Debug)]
+struct Coordinate {
+    start: usize,
+    end: usize,
+}
+
+impl Coordinate {
+    fn overlaps(&self, other: &Coordinate) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+#[derive(Debug)]
+pub struct Brick {
+    x: Coordinate,
+    y: Coordinate,
+    z: Coordinate,
+    rests_on: Vec<usize>,
+    fallen: usize, // cubes the brick fell from where the input put it
+}
+
+impl Brick {
+    fn overlaps(&self, other: &Brick) -> bool {
+        self.x.overlaps(&other.x) && self.y.overlaps(&other.y)
+    }
+
+    fn rests_on(&self, other: &Brick) -> bool {
+        if self.z.start == 0 {
+            return false;
+        }
+
+        self.overlaps(other) && other.z.end == self.z.start - 1
+    }
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = HashMap<usize, Brick>;
+
+    const PART_ONE: &'static str = "Could disintegrate {} bricks individually";
+    const PART_TWO: &'static str = "Disintegrating each brick individually, {} bricks would fall";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input).into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(vec![side_views(input).artifact("bricks.svg")])
+    }
+
+    /// the bricks falling from where the input has them, a cube per step
+    fn stepper(&self, input: &Self::Input) -> Option<Box<dyn Stepper>> {
+        Some(Box::new(Settling::new(input)))
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<HashMap<usize, Brick>, AocError> {
+    let ctx = ParseContext::new(22, input_str);
+    let mut bricks = vec![];
+
+    for line in input_str.lines() {
+        let (from, to) = ctx.split_once(line, "~")?;
+        let from: Vec<&str> = from.split(",").collect();
+        let to: Vec<&str> = to.split(",").collect();
+        if from.len() != 3 || to.len() != 3 {
+            return Err(ctx.error(line, "expected two x,y,z coordinates"));
+        }
+
+        let mut coordinates = vec![];
+        for (start, end) in from.into_iter().zip(to) {
+            let coordinate = Coordinate {
+                start: ctx.number(start)?,
+                end: ctx.number(end)?,
+            };
+            if coordinate.start > coordinate.end {
+                return Err(ctx.error(end, "brick must not end before it starts"));
+            }
+            coordinates.push(coordinate);
+        }
+
+        bricks.push(Brick {
+            x: coordinates[0],
+            y: coordinates[1],
+            z: coordinates[2],
+            rests_on: Vec::new(),
+            fallen: 0,
+        });
+    }
+
+    bricks.sort_by(|a: &Brick, b| a.z.start.cmp(&b.z.start));
+
+    let mut dropped_bricks: HashMap<usize, Brick> = HashMap::new();
+
+    for (idx, brick) in bricks.into_iter().enumerate() {
+        dropped_bricks.insert(idx, drop_brick(&dropped_bricks, brick));
+    }
+
+    Ok(dropped_bricks)
+}
+
+fn part_one(bricks: &HashMap<usize, Brick>) -> usize {
+    bricks.len() - sole_supports(bricks).len()
+}
+
+/// bricks that some other brick rests on alone, the rest can be disintegrated
+fn sole_supports(bricks: &HashMap<usize, Brick>) -> HashSet<usize> {
+    bricks
+        .values()
+        .filter(|brick| brick.rests_on.len() == 1)
+        .map(|brick| brick.rests_on[0])
+        .collect()
+}
+
+fn part_two(bricks: &HashMap<usize, Brick>) -> usize {
+    let mut dropping_sum = 0;
+
+    for &brick_id in bricks.keys() {
+        let mut dropping: Vec<usize> = bricks
+            .iter()
+            .filter(|(_, brick)| brick.rests_on.len() == 1 && brick.rests_on[0] == brick_id)
+            .map(|(&id, _)| id)
+            .collect();
+
+        loop {
+            let mut newly_dropping: Vec<usize> = bricks
+                .iter()
+                .filter(|(id, brick)| {
+                    !dropping.contains(id)
+                        && !brick.rests_on.is_empty()
+                        && brick.rests_on.iter().all(|id| dropping.contains(id))
+                })
+                .map(|(&id, _)| id)
+                .collect();
+
+            if newly_dropping.is_empty() {
+                break;
+            }
+
+            dropping.append(&mut newly_dropping);
+        }
+
+        dropping_sum += dropping.len();
+    }
+
+    dropping_sum
+}
+
+fn drop_brick(resting_bricks: &HashMap<usize, Brick>, brick: Brick) -> Brick {
+    let mut new_z = 0;
+    resting_bricks
+        .values()
+        .filter(|resting| resting.overlaps(&brick))
+        .for_each(|resting| new_z = new_z.max(resting.z.end + 1));
+
+    // the settled bricks start at 0 rather than 1 like in the input
+    let mut brick = Brick {
+        x: brick.x,
+        y: brick.y,
+        z: Coordinate {
+            start: new_z,
+            end: new_z + brick.z.end - brick.z.start,
+        },
+        rests_on: Vec::new(),
+        fallen: brick.z.start.saturating_sub(new_z + 1),
+    };
+    brick.rests_on = resting_bricks
+        .iter()
+        .filter(|(_, other_brick)| brick.rests_on(other_brick))
+        .map(|(id, _)| *id)
+        .collect();
+    brick
+}
+
+/// the settled bricks from the front (x and z) and from the side (y and z), bricks nearer to the viewer are drawn
+/// over the ones behind them, those that can be disintegrated in green and the ones holding others up in orange
+fn side_views(bricks: &HashMap<usize, Brick>) -> Svg {
+    let width_x = bricks
+        .values()
+        .map(|brick| brick.x.end + 1)
+        .max()
+        .unwrap_or(1);
+    let width_y = bricks
+        .values()
+        .map(|brick| brick.y.end + 1)
+        .max()
+        .unwrap_or(1);
+    let height = bricks
+        .values()
+        .map(|brick| brick.z.end + 1)
+        .max()
+        .unwrap_or(1);
+    // the side view goes two cubes right of the front view
+    let side_offset = width_x + 2;
+
+    let mut svg = Svg::new((0.0, 0.0), ((side_offset + width_y) as f64, height as f64));
+    let supports = sole_supports(bricks);
+    let style = |id: &usize| {
+        let fill = if supports.contains(id) {
+            "#f08c1e"
+        } else {
+            "#5ac83c"
+        };
+        format!("fill:{fill};stroke:#303030;stroke-width:0.5")
+    };
+    // z goes up, y goes down in the drawing
+    let top = |brick: &Brick| (height - brick.z.end - 1) as f64;
+    let bottom = |brick: &Brick| (height - brick.z.start) as f64;
+
+    // the viewer looks along y onto the front and along x onto the side, both from below zero
+    let mut ids: Vec<&usize> = bricks.keys().collect();
+    ids.sort_by_key(|&id| std::cmp::Reverse((bricks[id].y.start, id)));
+    for id in &ids {
+        let brick = &bricks[id];
+        svg.rect(
+            (brick.x.start as f64, top(brick)),
+            ((brick.x.end + 1) as f64, bottom(brick)),
+            &style(id),
+        );
+    }
+    ids.sort_by_key(|&id| std::cmp::Reverse((bricks[id].x.start, id)));
+    for id in &ids {
+        let brick = &bricks[id];
+        svg.rect(
+            ((side_offset + brick.y.start) as f64, top(brick)),
+            ((side_offset + brick.y.end + 1) as f64, bottom(brick)),
+            &style(id),
+        );
+    }
+
+    let (_, caption) = svg.top_left();
+    let caption = caption + svg.pixels(20.0);
+    svg.text((0.0, caption), 14.0, "x, z");
+    svg.text((side_offset as f64, caption), 14.0, "y, z");
+    svg.text(
+        (0.0, height as f64 + svg.pixels(20.0)),
+        14.0,
+        &format!(
+            "{} bricks, {} can be disintegrated",
+            bricks.len(),
+            bricks.len() - supports.len()
+        ),
+    );
+    svg
+}
+
+/// all bricks falling at the same speed, each stopping where it comes to rest
+/// none can pass through another on the way: a brick above another one it overlaps also comes to rest above it
+struct Settling {
+    /// (x, y, z) where each brick comes to rest, and how far it falls to get there
+    bricks: Vec<([Coordinate; 3], usize)>,
+    width_x: usize,
+    width_y: usize,
+    height: usize,
+}
+
+impl Settling {
+    fn new(bricks: &HashMap<usize, Brick>) -> Settling {
+        let mut ids: Vec<&usize> = bricks.keys().collect();
+        ids.sort();
+        let bricks: Vec<([Coordinate; 3], usize)> = ids
+            .into_iter()
+            .map(|id| {
+                let brick = &bricks[id];
+                ([brick.x, brick.y, brick.z], brick.fallen)
+            })
+            .collect();
+
+        let extent = |axis: usize| {
+            bricks
+                .iter()
+                .map(|(coordinates, fallen)| {
+                    coordinates[axis].end + 1 + if axis == 2 { *fallen } else { 0 }
+                })
+                .max()
+                .unwrap_or(1)
+        };
+        Settling {
+            width_x: extent(0),
+            width_y: extent(1),
+            height: extent(2),
+            bricks,
+        }
+    }
+}
+
+impl Stepper for Settling {
+    fn last_step(&self) -> Option<usize> {
+        Some(
+            self.bricks
+                .iter()
+                .map(|(_, fallen)| *fallen)
+                .max()
+                .unwrap_or(0),
+        )
+    }
+
+    /// the bricks from the front (x and z) and from the side (y and z) like in the puzzle,
+    /// `v` for cubes still falling and `#` for cubes at rest, with the z of the input on the right
+    fn frame(&mut self, step: usize) -> Frame {
+        // 0 for empty, 1 for resting and 2 for falling, falling bricks are drawn over resting ones
+        let mut front = Grid::filled(self.width_x, self.height, 0);
+        let mut side = Grid::filled(self.width_y, self.height, 0);
+        let mut falling = 0;
+        for ([x, y, z], fallen) in &self.bricks {
+            let state = if *fallen > step { 2 } else { 1 };
+            falling += usize::from(*fallen > step);
+            let lift = fallen.saturating_sub(step);
+            for row in (self.height - z.end - lift - 1)..(self.height - z.start - lift) {
+                for col in x.start..=x.end {
+                    front[(row, col)] = front[(row, col)].max(state);
+                }
+                for col in y.start..=y.end {
+                    side[(row, col)] = side[(row, col)].max(state);
+                }
+            }
+        }
+
+        let symbol = |state: &u8| match state {
+            2 => 'v',
+            1 => '#',
+            _ => '.',
+        };
+        let mut view = format!("{:width$}   y\n", "x", width = self.width_x);
+        for (row, (front, side)) in front.rows().zip(side.rows()).enumerate() {
+            let front: String = front.iter().map(symbol).collect();
+            let side: String = side.iter().map(symbol).collect();
+            view += &format!("{front}   {side}  {}\n", self.height - row);
+        }
+        view += &format!(
+            "{}   {}  0\n",
+            "-".repeat(self.width_x),
+            "-".repeat(self.width_y)
+        );
+
+        Frame {
+            view,
+            counters: vec![
+                ("falling bricks", falling as u64),
+                ("bricks at rest", (self.bricks.len() - falling) as u64),
+            ],
+        }
+    }
+}
+
+/// the bricks where they came to rest, from the bottom up so they are dropped in the same order again
+fn serialize_input(bricks: &HashMap<usize, Brick>) -> String {
+    let mut ids: Vec<&usize> = bricks.keys().collect();
+    ids.sort_by_key(|&id| (bricks[id].z.start, id));
+
+    ids.into_iter()
+        .map(|id| {
+            let brick = &bricks[id];
+            format!(
+                "{},{},{}~{},{},{}\n",
+                brick.x.start, brick.y.start, brick.z.start, brick.x.end, brick.y.end, brick.z.end
+            )
+        })
+        .collect()
+}
+
+/// bricks of up to four cubes over a small area, at different heights without overlapping
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let area = size / 2 + 3;
+    let brick_count = size * 4;
+    let mut cubes = HashSet::new();
+    let mut input = String::new();
+
+    let mut placed = 0;
+    while placed < brick_count {
+        let start = [
+            rng.gen_range(0..area),
+            rng.gen_range(0..area),
+            rng.gen_range(1..=brick_count * 2),
+        ];
+        let mut end = start;
+        let axis = rng.gen_range(0..3);
+        end[axis] += rng.gen_range(0..4);
+
+        let brick_cubes: Vec<[usize; 3]> = (start[0]..=end[0])
+            .flat_map(|x| {
+                (start[1]..=end[1]).flat_map(move |y| (start[2]..=end[2]).map(move |z| [x, y, z]))
+            })
+            .collect();
+        if brick_cubes.iter().any(|cube| cubes.contains(cube)) {
+            continue;
+        }
+        cubes.extend(brick_cubes);
+        input += &format!(
+            "{},{},{}~{},{},{}\n",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        );
+        placed += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day22.parse(EXAMPLE).unwrap();
+        assert_eq!(Day22.part_one(&input).unwrap(), Answer::Unsigned(5));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day22.parse(EXAMPLE).unwrap();
+        assert_eq!(Day22.part_two(&input).unwrap(), Answer::Unsigned(7));
+    }
+
+    #[test]
+    fn bricks_fall_a_cube_per_step() {
+        let input = Day22.parse(EXAMPLE).unwrap();
+        let mut settling = Day22.stepper(&input).unwrap();
+        // G falls the furthest, three cubes from 8 down to 5
+        assert_eq!(settling.last_step(), Some(3));
+
+        let start = settling.frame(0);
+        assert_eq!(
+            start.counters,
+            vec![("falling bricks", 5), ("bricks at rest", 2)]
+        );
+        assert!(start
+            .view
+            .starts_with("x     y\n.v.   .v.  9\n.v.   .v.  8\n...   ...  7\n"));
+
+        let end = settling.frame(3);
+        assert_eq!(
+            end.counters,
+            vec![("falling bricks", 0), ("bricks at rest", 7)]
+        );
+        assert!(end
+            .view
+            .ends_with("###   #.#  2\n.#.   ###  1\n---   ---  0\n"));
+        assert!(end.view.contains("...   ...  7\n.#.   .#.  6\n"));
+    }
+
+    #[test]
+    fn side_views_of_settled_bricks() {
+        let input = Day22.parse(EXAMPLE).unwrap();
+        let drawn = side_views(&input).to_string();
+        // every brick is drawn once from the front and once from the side
+        assert_eq!(drawn.matches("<rect").count(), 14);
+        assert_eq!(drawn.matches("fill:#5ac83c").count(), 10);
+        assert!(drawn.contains("7 bricks, 5 can be disintegrated"));
+        // brick A lies along y at the bottom of the stack, which is 6 cubes high once settled
+        assert!(drawn.contains(r#"<rect x="1" y="5" width="1" height="1""#));
+        assert!(drawn.contains(r#"<rect x="5" y="5" width="3" height="1""#));
+    }
+}
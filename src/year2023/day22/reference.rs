@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use super::Brick;
+
+/// (start, end) on the x, y and z axis
+type Cuboid = [(usize, usize); 3];
+
+/// lets the bricks fall from the lowest one up, one level at a time until they hit the ground or another brick,
+/// and counts the bricks that moved
+fn fall(mut bricks: Vec<Cuboid>) -> usize {
+    bricks.sort_by_key(|brick| brick[2].0);
+    let overlaps = |a: (usize, usize), b: (usize, usize)| a.0 <= b.1 && b.0 <= a.1;
+
+    let mut settled: Vec<Cuboid> = vec![];
+    let mut moved = 0;
+    for mut brick in bricks {
+        let start = brick[2].0;
+        while brick[2].0 > 0
+            && !settled.iter().any(|below| {
+                below[2].1 + 1 == brick[2].0
+                    && overlaps(below[0], brick[0])
+                    && overlaps(below[1], brick[1])
+            })
+        {
+            brick[2].0 -= 1;
+            brick[2].1 -= 1;
+        }
+        moved += (brick[2].0 != start) as usize;
+        settled.push(brick);
+    }
+    moved
+}
+
+/// the other bricks, with each one removed in turn
+fn without_each(bricks: &HashMap<usize, Brick>) -> impl Iterator<Item = Vec<Cuboid>> + '_ {
+    let cuboids: Vec<Cuboid> = bricks
+        .values()
+        .map(|brick| {
+            [brick.x, brick.y, brick.z].map(|coordinate| (coordinate.start, coordinate.end))
+        })
+        .collect();
+    (0..cuboids.len()).map(move |removed| {
+        let mut rest = cuboids.clone();
+        rest.remove(removed);
+        rest
+    })
+}
+
+pub fn part_one(bricks: &HashMap<usize, Brick>) -> usize {
+    without_each(bricks)
+        .filter(|rest| fall(rest.clone()) == 0)
+        .count()
+}
+
+pub fn part_two(bricks: &HashMap<usize, Brick>) -> usize {
+    without_each(bricks).map(fall).sum()
+}
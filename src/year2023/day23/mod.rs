@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::dot::Dot;
+use crate::util::geometry::Direction;
+use crate::util::graph::{self, Graph};
+use crate::util::grid::Grid;
+use crate::util::image::{self, Image};
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = Grid<char>;
+
+    const PART_ONE: &'static str = "Longest hike has {} steps";
+    const PART_TWO: &'static str = "Longest hike with scramling up hills has {} steps";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(longest_hike(input, false)?.into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(longest_hike(input, true)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(input.to_string())
+    }
+
+    /// the longest hikes of both parts in red, slopes in blue, and the junctions they are searched on
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        let mut artifacts = vec![];
+        for (scramble, prefix) in [(false, ""), (true, "scrambling_")] {
+            let hikes = Hikes::new(input, scramble);
+            artifacts.push(
+                draw_hike(input, &longest_hike_tiles(&hikes)?).artifact(&format!("{prefix}hike")),
+            );
+            artifacts.push(junction_graph(&hikes).artifact(&format!("{prefix}junctions.dot")));
+        }
+        Ok(artifacts)
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Grid<char>, AocError> {
+    let ctx = ParseContext::new(23, input_str);
+    let map = Grid::parse_chars(&ctx, input_str, ".#>v", "a map")?;
+    let (width, height) = (map.width(), map.height());
+
+    // the hike goes from the second tile of the first row to the second to last tile of the last row
+    if height < 2 || width < 3 || map[(0, 1)] != '.' || map[(height - 1, width - 2)] != '.' {
+        return Err(ctx.error(input_str, "expected paths at the top left and bottom right"));
+    }
+
+    Ok(map)
+}
+
+/// the paths on the map, slopes can only be walked down unless scrambling up is allowed (part two)
+struct Trails<'a> {
+    map: &'a Grid<char>,
+    scramble: bool,
+}
+
+impl Graph for Trails<'_> {
+    type Node = (usize, usize);
+
+    fn neighbors(&self, &position: &(usize, usize)) -> Vec<((usize, usize), u64)> {
+        let directions = match Direction::from_arrow(self.map[position]) {
+            Some(slope) if !self.scramble => vec![slope],
+            _ => Direction::ALL.to_vec(),
+        };
+
+        directions
+            .into_iter()
+            .filter_map(|direction| self.map.step(position, direction))
+            .filter(|&next| self.map[next] != '#')
+            .map(|next| (next, 1))
+            .collect()
+    }
+}
+
+/// the junctions each junction leads to, with the steps of the corridor between them
+type Corridors = HashMap<(usize, usize), Vec<((usize, usize), u64)>>;
+
+/// the trails contracted to the corridors between junctions
+/// trying every path is only feasible once the long corridors between junctions are single edges
+struct Hikes<'a> {
+    trails: Trails<'a>,
+    junctions: Vec<(usize, usize)>,
+    corridors: Corridors,
+}
+
+impl<'a> Hikes<'a> {
+    fn new(map: &'a Grid<char>, scramble: bool) -> Self {
+        let trails = Trails { map, scramble };
+        let junctions = junctions(map);
+        let corridors = graph::contract(&trails, &junctions);
+        Hikes {
+            trails,
+            junctions,
+            corridors,
+        }
+    }
+}
+
+fn longest_hike(map: &Grid<char>, scramble: bool) -> Result<u64, AocError> {
+    let hikes = Hikes::new(map, scramble);
+    graph::longest_path(&hikes.corridors, hikes.junctions[0], &hikes.junctions[1])
+        .ok_or_else(no_hike)
+}
+
+/// start and end first, then the path tiles with more than two ways to go
+fn junctions(map: &Grid<char>) -> Vec<(usize, usize)> {
+    let start = (0, 1);
+    let end = (map.height() - 1, map.width() - 2);
+    let mut junctions = vec![start, end];
+
+    for (position, &tile) in map.iter() {
+        if tile == '.'
+            && position != start
+            && position != end
+            && map
+                .neighbors4(position)
+                .filter(|&neighbor| map[neighbor] != '#')
+                .count()
+                >= 3
+        {
+            junctions.push(position);
+        }
+    }
+    junctions
+}
+
+/// every tile of the longest hike, from the start to the end
+fn longest_hike_tiles(hikes: &Hikes) -> Result<Vec<(usize, usize)>, AocError> {
+    let (trails, junctions) = (&hikes.trails, &hikes.junctions);
+    let route =
+        graph::longest_route(&hikes.corridors, junctions[0], &junctions[1]).ok_or_else(no_hike)?;
+
+    let mut tiles = vec![junctions[0]];
+    for pair in route.nodes.windows(2) {
+        // there can be more than one corridor between two junctions, the hike takes the longest
+        let corridor = trails
+            .neighbors(&pair[0])
+            .into_iter()
+            .filter_map(|first| {
+                graph::corridor(trails, &pair[0], first, |node| junctions.contains(node))
+            })
+            .filter(|corridor| corridor.nodes.last() == Some(&pair[1]))
+            .max_by_key(|corridor| corridor.cost)
+            .ok_or_else(|| {
+                AocError::solve(
+                    23,
+                    format!(
+                        "no corridor leads from {:?} to {:?} on the hike",
+                        pair[0], pair[1]
+                    ),
+                )
+            })?;
+        tiles.extend(corridor.nodes);
+    }
+    Ok(tiles)
+}
+
+/// the junctions named by their row and column, with the length of the corridors between them
+/// going downhill the corridors are one way, scrambling up slopes they go both ways
+fn junction_graph(hikes: &Hikes) -> Dot {
+    let (junctions, corridors) = (&hikes.junctions, &hikes.corridors);
+    let id = |(row, col): (usize, usize)| format!("{row},{col}");
+
+    let mut dot = if hikes.trails.scramble {
+        Dot::undirected()
+    } else {
+        Dot::directed()
+    };
+    dot.node(
+        &id(junctions[0]),
+        &[
+            ("label", "start"),
+            ("style", "filled"),
+            ("fillcolor", "palegreen"),
+        ],
+    );
+    dot.node(
+        &id(junctions[1]),
+        &[
+            ("label", "end"),
+            ("style", "filled"),
+            ("fillcolor", "lightcoral"),
+        ],
+    );
+    for junction in junctions {
+        for (next, steps) in &corridors[junction] {
+            // both ends know the corridor, it's only drawn once
+            if hikes.trails.scramble && next < junction {
+                continue;
+            }
+            dot.edge(&id(*junction), &id(*next), &[("label", &steps.to_string())]);
+        }
+    }
+    dot
+}
+
+fn draw_hike(map: &Grid<char>, tiles: &[(usize, usize)]) -> Image {
+    let mut colors = map.map(|&tile| match tile {
+        '#' => image::DARK_GRAY,
+        '.' => image::BLACK,
+        _ => image::BLUE,
+    });
+    for &tile in tiles {
+        colors[tile] = image::RED;
+    }
+    Image::render(&colors, 4, |&color| color)
+}
+
+fn no_hike() -> AocError {
+    AocError::solve(23, "there is no hike to the bottom right")
+}
+
+/// junctions on a lattice with straight trails to the next junctions right and down, slopes lead away from each
+/// junction like in the real inputs, so going downhill always heads to the bottom right
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let junctions = (size / 2).clamp(2, 5);
+    let spacing = rng.gen_range(3..=6);
+    let side = (junctions - 1) * spacing + 3;
+    let mut map = Grid::filled(side, side, '#');
+    map[(0, 1)] = '.';
+    map[(side - 1, side - 2)] = '.';
+
+    for i in 0..junctions {
+        for j in 0..junctions {
+            let (row, col) = (1 + i * spacing, 1 + j * spacing);
+            map[(row, col)] = '.';
+
+            // every junction but the last one needs a way on
+            let can_go_right = j + 1 < junctions;
+            let can_go_down = i + 1 < junctions;
+            let mut right = can_go_right && rng.gen_bool(0.8);
+            let down = can_go_down && (rng.gen_bool(0.8) || !right);
+            right = right || (can_go_right && !down);
+
+            for step in 1..spacing {
+                let slope = step == 1 || step == spacing - 1;
+                if right {
+                    map[(row, col + step)] = if slope { '>' } else { '.' };
+                }
+                if down {
+                    map[(row + step, col)] = if slope { 'v' } else { '.' };
+                }
+            }
+        }
+    }
+    map.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(Day23.part_one(&input).unwrap(), Answer::Unsigned(94));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(Day23.part_two(&input).unwrap(), Answer::Unsigned(154));
+    }
+
+    #[test]
+    fn hike_tiles_are_one_more_than_steps() {
+        let input = Day23.parse(EXAMPLE).unwrap();
+        for (scramble, steps) in [(false, 94), (true, 154)] {
+            let tiles = longest_hike_tiles(&Hikes::new(&input, scramble)).unwrap();
+            assert_eq!(tiles.len(), steps + 1);
+            assert_eq!(tiles.iter().collect::<HashSet<_>>().len(), tiles.len());
+            assert_eq!(tiles.last(), Some(&(input.height() - 1, input.width() - 2)));
+        }
+    }
+
+    #[test]
+    fn start_and_end_can_be_junctions() {
+        let input = Day23.parse("...\n#.#\n...\n").unwrap();
+        assert_eq!(junctions(&input), vec![(0, 1), (2, 1)]);
+        assert_eq!(Day23.part_two(&input).unwrap(), Answer::Unsigned(2));
+        assert_eq!(Day23.emit(&input).unwrap().len(), 4);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::util::grid::Grid;
+
+/// longest way from `position` to `end` over tiles that aren't visited yet, trying every single path
+fn longest_hike(
+    map: &Grid<char>,
+    position: (usize, usize),
+    end: (usize, usize),
+    scramble: bool,
+    visited: &mut Grid<bool>,
+) -> Option<u64> {
+    if position == end {
+        return Some(0);
+    }
+
+    let offsets: &[(isize, isize)] = match map[position] {
+        '>' if !scramble => &[(0, 1)],
+        'v' if !scramble => &[(1, 0)],
+        _ => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+    };
+    let next_tiles: Vec<(usize, usize)> = offsets
+        .iter()
+        .filter_map(|&offset| map.offset(position, offset))
+        .filter(|&next| map[next] != '#' && !visited[next])
+        .collect();
+
+    visited[position] = true;
+    let longest = next_tiles
+        .into_iter()
+        .filter_map(|next| longest_hike(map, next, end, scramble, visited))
+        .map(|steps| steps + 1)
+        .max();
+    visited[position] = false;
+    longest
+}
+
+/// the only path tile in a row
+fn gap(row: &[char]) -> usize {
+    row.iter()
+        .position(|&tile| tile == '.')
+        .expect("the top and bottom rows have a gap")
+}
+
+fn hike(map: &Grid<char>, scramble: bool) -> u64 {
+    let last_row = map.height() - 1;
+    let start = (0, gap(map.row(0)));
+    let end = (last_row, gap(map.row(last_row)));
+    let mut visited = map.map(|_| false);
+
+    longest_hike(map, start, end, scramble, &mut visited).expect("the end can be reached")
+}
+
+pub fn part_one(map: &Grid<char>) -> u64 {
+    hike(map, false)
+}
+
+pub fn part_two(map: &Grid<char>) -> u64 {
+    hike(map, true)
+}
@@ -0,0 +1,433 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::util::svg::Svg;
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+#[derive(Debug, Clone)]
+pub struct Hailstone {
+    position: (i128, i128, i128),
+    speed: (i128, i128, i128),
+}
+
+/// largest rock speed (in x and y) part 2 tries before giving up
+const MAX_SEARCH: i128 = 1000;
+
+/// lowest and highest x and y of the area part 1 looks for crossing paths in
+const TEST_AREA: (f64, f64) = (200000000000000.0, 400000000000000.0);
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = Vec<Hailstone>;
+
+    const PART_ONE: &'static str = "{} intersections in test area";
+    const PART_TWO: &'static str = "Sum of stone coordinates is {}";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input, TEST_AREA.0, TEST_AREA.1).into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_two(input)?.into())
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    /// the paths of the hailstones in x and y through the test area, with the points where they cross
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        Ok(vec![
+            trajectories(input, TEST_AREA.0, TEST_AREA.1).artifact("trajectories.svg")
+        ])
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        Some(Ok(match part {
+            1 => reference::part_one(input).into(),
+            _ => reference::part_two(input).into(),
+        }))
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Vec<Hailstone>, AocError> {
+    let ctx = ParseContext::new(24, input_str);
+    input_str
+        .lines()
+        .map(|line| {
+            let (position, speed) = ctx.split_once(line, "@")?;
+            let position = parse_vector(&ctx, position)?;
+            let speed = parse_vector(&ctx, speed)?;
+            Ok(Hailstone { position, speed })
+        })
+        .collect()
+}
+
+/// parses "x, y, z"
+fn parse_vector(ctx: &ParseContext, vector_str: &str) -> Result<(i128, i128, i128), AocError> {
+    let values: Vec<&str> = vector_str.split(",").collect();
+    if values.len() != 3 {
+        return Err(ctx.error(vector_str, "expected x, y and z"));
+    }
+    Ok((
+        ctx.number(values[0])?,
+        ctx.number(values[1])?,
+        ctx.number(values[2])?,
+    ))
+}
+
+fn part_one(hailstones: &[Hailstone], test_area_min: f64, test_area_max: f64) -> u32 {
+    test_area_intersections(hailstones, test_area_min, test_area_max).len() as u32
+}
+
+/// points in the test area where the future paths of two hailstones cross, ignoring z
+fn test_area_intersections(
+    hailstones: &[Hailstone],
+    test_area_min: f64,
+    test_area_max: f64,
+) -> Vec<(f64, f64)> {
+    let mut intersections = vec![];
+
+    for (idx, hailstone) in hailstones.iter().enumerate() {
+        for other_hail in &hailstones[idx + 1..] {
+            if let Some(intersection) = xy_intersection(hailstone, other_hail) {
+                if intersection.0 >= test_area_min
+                    && intersection.0 <= test_area_max
+                    && intersection.1 >= test_area_min
+                    && intersection.1 <= test_area_max
+                {
+                    intersections.push(intersection);
+                }
+            }
+        }
+    }
+
+    intersections
+}
+
+/// the test area is drawn as 1000 by 1000, the real coordinates are too large for most SVG viewers
+fn trajectories(hailstones: &[Hailstone], test_area_min: f64, test_area_max: f64) -> Svg {
+    let scale = 1000.0 / (test_area_max - test_area_min);
+    let project = |(x, y): (f64, f64)| ((x - test_area_min) * scale, (y - test_area_min) * scale);
+
+    let mut svg = Svg::new((0.0, 0.0), (1000.0, 1000.0));
+    svg.rect((0.0, 0.0), (1000.0, 1000.0), "fill:#eef3fb;stroke:#8090b0");
+
+    for hailstone in hailstones {
+        if let Some((from, to)) = path_in_area(hailstone, test_area_min, test_area_max) {
+            svg.line(
+                project(from),
+                project(to),
+                "stroke:#2060c0;stroke-opacity:0.6",
+            );
+        }
+    }
+
+    let intersections = test_area_intersections(hailstones, test_area_min, test_area_max);
+    for &intersection in &intersections {
+        svg.dot(project(intersection), 2.0, "fill:#d03020");
+    }
+
+    let (left, top) = svg.top_left();
+    svg.text(
+        (left + svg.pixels(10.0), top + svg.pixels(24.0)),
+        16.0,
+        &format!(
+            "{} intersections in the test area from {test_area_min} to {test_area_max}",
+            intersections.len()
+        ),
+    );
+    svg
+}
+
+/// start and end of the part of the future path of a hailstone (in x and y) inside the test area,
+/// None if it never gets there
+fn path_in_area(
+    hailstone: &Hailstone,
+    test_area_min: f64,
+    test_area_max: f64,
+) -> Option<((f64, f64), (f64, f64))> {
+    // times the hailstone is inside the area in both x and y
+    let (mut enter, mut leave) = (0.0_f64, f64::INFINITY);
+    for (position, speed) in [
+        (hailstone.position.0, hailstone.speed.0),
+        (hailstone.position.1, hailstone.speed.1),
+    ] {
+        let (position, speed) = (position as f64, speed as f64);
+        if speed == 0.0 {
+            if position < test_area_min || position > test_area_max {
+                return None;
+            }
+        } else {
+            let to_min = (test_area_min - position) / speed;
+            let to_max = (test_area_max - position) / speed;
+            enter = enter.max(to_min.min(to_max));
+            leave = leave.min(to_min.max(to_max));
+        }
+    }
+    // a hailstone that doesn't move in x or y is only a point
+    if enter > leave || leave.is_infinite() {
+        return None;
+    }
+
+    let at = |time: f64| {
+        (
+            hailstone.position.0 as f64 + time * hailstone.speed.0 as f64,
+            hailstone.position.1 as f64 + time * hailstone.speed.1 as f64,
+        )
+    };
+    Some((at(enter), at(leave)))
+}
+
+fn part_two(hailstones: &[Hailstone]) -> Result<i128, AocError> {
+    if hailstones.len() < 2 {
+        return Err(AocError::solve(
+            24,
+            "need at least two hailstones to find the rock",
+        ));
+    }
+
+    let mut stone_start_xy = (0, 0);
+    let mut stone_z = 0;
+
+    let mut last_search_to = 0;
+    let mut search_to = 10;
+    let mut success = false;
+
+    // this works as follows:
+    // - try options for x and y velocity of the stone roughly in order of ascending magnitude
+    //      (start with -10 to 10 for x and y each, then do -20 to 20 - skipping -10 to 10 - and so on)
+    // - for each velocity option:
+    //      - adjust speeds of the hailstones so stone is stationary and hail is moving relative to stone
+    //      - try to find xy point where all hailstones xy-intersect (this must be where the stone starts, as it does not move in this relative version)
+    //      - if even one hailstone does not intersect, this velocity cannot work, try the next one
+    //      - once you've found an intersection point, check if working z coordinate exists (it almost certainly does, but you gotta check)
+    //      - add up the coordinates you've found and you're done!
+    while !success {
+        if search_to > MAX_SEARCH {
+            return Err(AocError::solve(
+                24,
+                format!("no rock velocity up to {MAX_SEARCH} hits every hailstone"),
+            ));
+        }
+        for delta_x in (search_to * -1)..search_to {
+            for delta_y in (search_to * -1)..search_to {
+                // skip what you've already done
+                if i128::abs(delta_x) < last_search_to && i128::abs(delta_y) < last_search_to {
+                    // already checked in a previous loop, don't do it again
+                    continue;
+                }
+
+                // adjust hailstone velocity vectors by the velocity we're trying for the stone
+                let adjusted_hailstones: Vec<Hailstone> = hailstones
+                    .iter()
+                    .map(|hailstone| Hailstone {
+                        position: hailstone.position,
+                        speed: (hailstone.speed.0 - delta_x, hailstone.speed.1 - delta_y, 0),
+                    })
+                    .collect();
+
+                // search for xy collisions first, as it's quicker and simpler than checking x, y and z (and already implemented)
+                success = true;
+                stone_start_xy = (0, 0);
+
+                let hailstone = &adjusted_hailstones[0];
+
+                // see if intersection of the stone we picked out with every other path is at the same point
+                // parallel lines count as valid intersections here (as they will definitely also be the same at the point where all others intersect)
+                for other_hail in adjusted_hailstones.iter().skip(1) {
+                    if let Some(intersection) = xy_intersection(&hailstone, other_hail) {
+                        if stone_start_xy != (intersection.0 as i128, intersection.1 as i128)
+                            && stone_start_xy != (0, 0)
+                            && intersection != (f64::INFINITY, f64::INFINITY)
+                        {
+                            success = false;
+                            break;
+                        } else if stone_start_xy == (0, 0)
+                            && intersection != (f64::INFINITY, f64::INFINITY)
+                        {
+                            stone_start_xy = (intersection.0 as i128, intersection.1 as i128);
+                        }
+                    } else {
+                        success = false;
+                        break;
+                    }
+                }
+
+                // if we've made it here without failing, there's a point all hailstones pass in the relative version of events
+                if success {
+                    // check that a working z coordinate exists
+
+                    // get intercept time and z coordinate of first hailstone
+                    let Some(time_to_intercept_0) = (stone_start_xy.0
+                        - adjusted_hailstones[0].position.0)
+                        .checked_div(adjusted_hailstones[0].speed.0)
+                    else {
+                        success = false;
+                        continue;
+                    };
+                    let intercept_z_0 =
+                        hailstones[0].position.2 + (time_to_intercept_0 * hailstones[0].speed.2);
+
+                    let mut previous_slope = 0;
+
+                    // for each other hailstone: calculate slope of z curve and check it's constant
+                    for i in 1..adjusted_hailstones.len() {
+                        let Some(time_to_intercept) = (stone_start_xy.0
+                            - adjusted_hailstones[i].position.0)
+                            .checked_div(adjusted_hailstones[i].speed.0)
+                        else {
+                            success = false;
+                            break;
+                        };
+                        let intercept_z =
+                            hailstones[i].position.2 + (time_to_intercept * hailstones[i].speed.2);
+
+                        let Some(slope) = (intercept_z - intercept_z_0)
+                            .checked_div(time_to_intercept - time_to_intercept_0)
+                        else {
+                            success = false;
+                            break;
+                        };
+
+                        if slope != previous_slope && previous_slope != 0 {
+                            // guess finding a z didn't work out after all... keep trying other velocities
+                            success = false;
+                        } else {
+                            previous_slope = slope;
+                        }
+                    }
+
+                    // calculating the stone starting point is now easy, since we have a point with corresponding time and the slope of the z position curve
+                    stone_z = intercept_z_0 - (previous_slope * time_to_intercept_0);
+
+                    if success {
+                        break;
+                    }
+                }
+            }
+            if success {
+                break;
+            }
+        }
+        // search larger magnitudes in the next iteration
+        last_search_to = search_to;
+        search_to += 10;
+    }
+
+    Ok(stone_start_xy.0 + stone_start_xy.1 + stone_z)
+}
+
+/// entirely ignores z component of coordinates and speed
+/// returns None if intersection is in the past
+/// returns (inf, inf) if lines are parallel
+/// returns coordinates of xy intersection otherwise
+fn xy_intersection(a: &Hailstone, b: &Hailstone) -> Option<(f64, f64)> {
+    // parallel lines or the same line
+    if a.speed.0 * b.speed.1 == a.speed.1 * b.speed.0 {
+        return Some((f64::INFINITY, f64::INFINITY));
+    }
+
+    // formula for intersection of two lines given as vector equations
+    // lambda refers to this equation for hailstone a: (x, y) = (x0, y0) + lambda * (xspeed, yspeed)
+    let lambda = (((a.position.1 - b.position.1) * b.speed.0)
+        - ((a.position.0 - b.position.0) * b.speed.1)) as f64
+        / ((a.speed.0 * b.speed.1) - (a.speed.1 * b.speed.0)) as f64;
+
+    // same for hailstone b, both have to get there now or in the future
+    // (comparing the direction of the x speed misses hailstones that don't move in x)
+    let mu = (((a.position.1 - b.position.1) * a.speed.0)
+        - ((a.position.0 - b.position.0) * a.speed.1)) as f64
+        / ((a.speed.0 * b.speed.1) - (a.speed.1 * b.speed.0)) as f64;
+
+    if lambda < 0.0 || mu < 0.0 {
+        return None;
+    }
+
+    // now put lambda into hailstone a equation to get coordinates
+    let intersection_x = a.position.0 as f64 + (lambda * a.speed.0 as f64);
+    let intersection_y = a.position.1 as f64 + (lambda * a.speed.1 as f64);
+
+    Some((intersection_x, intersection_y))
+}
+
+fn serialize_input(hailstones: &[Hailstone]) -> String {
+    hailstones
+        .iter()
+        .map(|Hailstone { position, speed }| {
+            format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                position.0, position.1, position.2, speed.0, speed.1, speed.2
+            )
+        })
+        .collect()
+}
+
+/// hailstones that a rock thrown from the part 1 test area with a small velocity hits one after the other
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let rock: [i128; 3] = [0; 3].map(|_| rng.gen_range(200_000_000_000_000..400_000_000_000_000));
+    let rock_speed: [i128; 3] = [0; 3].map(|_| rng.gen_range(-10..=10));
+
+    let mut input = String::new();
+    for hailstone in 1..=size as i128 + 3 {
+        let time = hailstone * 1000 + rng.gen_range(0..1000);
+        // part 2 needs every hailstone to move in x relative to the rock
+        let speed = loop {
+            let speed = [0; 3].map(|_| rng.gen_range(-100..=100));
+            if speed[0] != rock_speed[0] {
+                break speed;
+            }
+        };
+        let position: [i128; 3] =
+            std::array::from_fn(|axis| rock[axis] + time * (rock_speed[axis] - speed[axis]));
+        input += &format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            position[0], position[1], position[2], speed[0], speed[1], speed[2]
+        );
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        // the example uses a much smaller test area
+        let input = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!(part_one(&input, 7.0, 27.0), 2);
+    }
+
+    #[test]
+    fn draws_crossings_in_test_area() {
+        let input = Day24.parse(EXAMPLE).unwrap();
+        let drawn = trajectories(&input, 7.0, 27.0).to_string();
+        assert_eq!(drawn.matches("<circle").count(), 2);
+        assert_eq!(drawn.matches("<line").count(), input.len());
+        assert!(drawn.contains("2 intersections in the test area from 7 to 27"));
+        // the crossing of the first two hailstones at x=14.333, y=15.333
+        assert!(drawn.contains(r#"cx="366.66"#));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!(Day24.part_two(&input).unwrap(), Answer::Signed(47));
+    }
+}
@@ -0,0 +1,112 @@
+use super::{Hailstone, MAX_SEARCH};
+
+const TEST_AREA: (i128, i128) = (200_000_000_000_000, 400_000_000_000_000);
+
+/// times (as numerators over a shared positive denominator) at which two hailstones moving with `speeds` reach
+/// the point where their paths cross in x and y, None for parallel paths
+fn crossing_times(
+    positions: [(i128, i128); 2],
+    speeds: [(i128, i128); 2],
+) -> Option<(i128, i128, i128)> {
+    // solve positions[0] + t * speeds[0] = positions[1] + s * speeds[1] with Cramer's rule
+    let (dx, dy) = (
+        positions[1].0 - positions[0].0,
+        positions[1].1 - positions[0].1,
+    );
+    let det = speeds[1].0 * speeds[0].1 - speeds[0].0 * speeds[1].1;
+    if det == 0 {
+        return None;
+    }
+    let t = speeds[1].0 * dy - speeds[1].1 * dx;
+    let s = speeds[0].0 * dy - speeds[0].1 * dx;
+    Some((t * det.signum(), s * det.signum(), det.abs()))
+}
+
+fn xy(vector: (i128, i128, i128)) -> (i128, i128) {
+    (vector.0, vector.1)
+}
+
+/// counts with exact fractions instead of floats
+pub fn part_one(hailstones: &[Hailstone]) -> u32 {
+    let mut crossings = 0;
+    for (idx, a) in hailstones.iter().enumerate() {
+        for b in &hailstones[idx + 1..] {
+            let positions = [xy(a.position), xy(b.position)];
+            let Some((t, s, det)) = crossing_times(positions, [xy(a.speed), xy(b.speed)]) else {
+                continue;
+            };
+            if t < 0 || s < 0 {
+                continue;
+            }
+            let x = a.position.0 * det + t * a.speed.0;
+            let y = a.position.1 * det + t * a.speed.1;
+            let inside = |value: i128| TEST_AREA.0 * det <= value && value <= TEST_AREA.1 * det;
+            if inside(x) && inside(y) {
+                crossings += 1;
+            }
+        }
+    }
+    crossings
+}
+
+/// sum of the coordinates of the rock's start, if a rock moving with (vx, vy) in x and y hits every hailstone
+/// at whole positions and times
+fn rock(hailstones: &[Hailstone], vx: i128, vy: i128) -> Option<i128> {
+    // seen from the rock all hailstones fly through its start, so that's where the first two cross
+    let relative = |hailstone: &Hailstone| (hailstone.speed.0 - vx, hailstone.speed.1 - vy);
+    let (first, second) = (&hailstones[0], &hailstones[1]);
+    let positions = [xy(first.position), xy(second.position)];
+    let (t, _, det) = crossing_times(positions, [relative(first), relative(second)])?;
+    if t % det != 0 {
+        return None;
+    }
+    let t = t / det;
+    let x = first.position.0 + t * relative(first).0;
+    let y = first.position.1 + t * relative(first).1;
+
+    // the time every hailstone gets there, which has to be the same in x and y
+    let mut times = vec![];
+    for hailstone in hailstones {
+        let (speed_x, speed_y) = relative(hailstone);
+        let (dx, dy) = (x - hailstone.position.0, y - hailstone.position.1);
+        let time = match (speed_x, speed_y) {
+            (0, 0) => return None,
+            (0, _) if dx == 0 && dy % speed_y == 0 => dy / speed_y,
+            (_, _) if speed_x != 0 && dx % speed_x == 0 => dx / speed_x,
+            _ => return None,
+        };
+        if time < 0 || dx != time * speed_x || dy != time * speed_y {
+            return None;
+        }
+        times.push(time);
+    }
+
+    // z follows from two hailstones that are hit at different times, and has to work for all of them
+    let z_at = |idx: usize| hailstones[idx].position.2 + times[idx] * hailstones[idx].speed.2;
+    let later = (1..hailstones.len()).find(|&idx| times[idx] != times[0])?;
+    let dz = z_at(later) - z_at(0);
+    let dt = times[later] - times[0];
+    if dz % dt != 0 {
+        return None;
+    }
+    let vz = dz / dt;
+    let z = z_at(0) - vz * times[0];
+    if (0..hailstones.len()).any(|idx| z + vz * times[idx] != z_at(idx)) {
+        return None;
+    }
+
+    Some(x + y + z)
+}
+
+/// tries every rock velocity in x and y, the smaller ones first
+pub fn part_two(hailstones: &[Hailstone]) -> i128 {
+    let speeds: Vec<i128> = (0..=MAX_SEARCH)
+        .flat_map(|speed| [speed, -speed])
+        .skip(1)
+        .collect();
+    speeds
+        .iter()
+        .flat_map(|&vx| speeds.iter().map(move |&vy| (vx, vy)))
+        .find_map(|(vx, vy)| rock(hailstones, vx, vy))
+        .expect("some rock velocity hits every hailstone")
+}
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+use crate::generate;
+use crate::util::dot::Dot;
+use crate::util::graph::{self, Cut};
+use crate::{Answer, AocError, Artifact, ParseContext, Solver};
+
+#[cfg(feature = "reference")]
+mod reference;
+
+/// how often Karger's algorithm is tried before giving up on finding a cut of 3
+const MAX_ATTEMPTS: usize = 10000;
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Vec<(String, String)>;
+
+    const PART_ONE: &'static str = "Result of multiplying subset sizes is {}";
+    const PART_TWO: &'static str = "";
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, _input: &Self::Input) -> Result<Answer, AocError> {
+        // there is no puzzle for day 25 part 2
+        Ok(Answer::None)
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate_input(rng, size))
+    }
+
+    fn serialize(&self, input: &Self::Input) -> Option<String> {
+        Some(serialize_input(input))
+    }
+
+    fn emit(&self, input: &Self::Input) -> Result<Vec<Artifact>, AocError> {
+        let cut = three_wire_cut(input)?;
+        Ok(vec![component_graph(input, &cut).artifact("components.dot")])
+    }
+
+    #[cfg(feature = "reference")]
+    fn reference(&self, input: &Self::Input, part: u8) -> Option<Result<Answer, AocError>> {
+        match part {
+            1 => Some(Ok(reference::part_one(input).into())),
+            _ => None,
+        }
+    }
+}
+
+/// returns the wires as pairs of component names
+fn parse_input(input_str: &str) -> Result<Vec<(String, String)>, AocError> {
+    let ctx = ParseContext::new(25, input_str);
+    let mut edges = Vec::new();
+
+    for line in input_str.lines() {
+        let (src, dest_str) = ctx.split_once(line, ": ")?;
+        if !is_component_name(src) {
+            return Err(ctx.error(src, "expected a component name"));
+        }
+
+        for dest in dest_str.split(" ") {
+            if !is_component_name(dest) || dest == src {
+                return Err(ctx.error(dest, "expected the name of another component"));
+            }
+
+            edges.push((src.to_string(), dest.to_string()));
+        }
+    }
+
+    Ok(edges)
+}
+
+fn is_component_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn part_one(wires: &[(String, String)]) -> Result<usize, AocError> {
+    let cut = three_wire_cut(wires)?;
+    Ok(cut.sides.0.len() * cut.sides.1.len())
+}
+
+fn three_wire_cut(wires: &[(String, String)]) -> Result<Cut<String>, AocError> {
+    // Karger's algorithm is probabilistic, so it may need many attempts to find the cut of 3 the puzzle guarantees
+    let cut = graph::min_cut(wires, 3, MAX_ATTEMPTS)
+        .ok_or_else(|| AocError::solve(25, "there are not enough components to split"))?;
+
+    match cut.edges.len() {
+        0 => Err(AocError::solve(
+            25,
+            "the components are already split into groups",
+        )),
+        3 => Ok(cut),
+        // every cut is at least as big as the minimum cut, so a smaller one is the minimum
+        cut_size @ 1..=2 => Err(AocError::solve(
+            25,
+            format!("the components can be split by cutting {cut_size} wires"),
+        )),
+        _ => Err(AocError::solve(
+            25,
+            format!("no cut of 3 wires found in {MAX_ATTEMPTS} attempts"),
+        )),
+    }
+}
+
+/// the components colored by the group they end up in, the three wires to cut in red
+fn component_graph(wires: &[(String, String)], cut: &Cut<String>) -> Dot {
+    let mut dot = Dot::undirected();
+    dot.node_defaults(&[("style", "filled")]);
+    for (side, color) in [(&cut.sides.0, "lightblue"), (&cut.sides.1, "palegreen")] {
+        let mut components = side.clone();
+        components.sort();
+        for component in &components {
+            dot.node(component, &[("fillcolor", color)]);
+        }
+    }
+
+    for (from, to) in wires {
+        let is_cut = cut
+            .edges
+            .iter()
+            .any(|(a, b)| (a, b) == (from, to) || (a, b) == (to, from));
+        if is_cut {
+            dot.edge(from, to, &[("color", "red"), ("penwidth", "4")]);
+        } else {
+            dot.edge(from, to, &[]);
+        }
+    }
+    dot
+}
+
+/// one wire per line
+fn serialize_input(wires: &[(String, String)]) -> String {
+    wires
+        .iter()
+        .map(|(from, to)| format!("{from}: {to}\n"))
+        .collect()
+}
+
+/// two groups of components with lots of wires within each group, and just the three to cut between them
+fn generate_input(rng: &mut StdRng, size: usize) -> String {
+    let group_size = size + 6;
+    let names = generate::distinct_words(rng, generate::LOWERCASE, 3, 2 * group_size, &[]);
+
+    // four wires from every component make it very unlikely that a group can be split by cutting three
+    let mut wires = BTreeSet::new();
+    for group in [0, group_size] {
+        for component in 0..group_size {
+            for other in index::sample(rng, group_size - 1, 4) {
+                let other = if other >= component { other + 1 } else { other };
+                wires.insert((group + component.min(other), group + component.max(other)));
+            }
+        }
+    }
+    let mut cut = BTreeSet::new();
+    while cut.len() < 3 {
+        cut.insert((
+            rng.gen_range(0..group_size),
+            group_size + rng.gen_range(0..group_size),
+        ));
+    }
+    wires.extend(cut);
+
+    let mut connections: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (a, b) in wires {
+        let (from, to) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        connections.entry(from).or_default().push(&names[to]);
+    }
+    let mut lines: Vec<String> = connections
+        .into_iter()
+        .map(|(from, to)| format!("{}: {}\n", names[from], to.join(" ")))
+        .collect();
+    lines.shuffle(rng);
+
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example_input.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day25.parse(EXAMPLE).unwrap();
+        assert_eq!(Day25.part_one(&input).unwrap(), Answer::Unsigned(54));
+    }
+
+    #[test]
+    fn highlights_cut_wires() {
+        let input = Day25.parse(EXAMPLE).unwrap();
+        let dot = component_graph(&input, &three_wire_cut(&input).unwrap()).to_string();
+        assert_eq!(dot.matches(r#"[color="red", penwidth="4"]"#).count(), 3);
+        for wire in [
+            r#""pzl" -- "hfx""#,
+            r#""cmg" -- "bvb""#,
+            r#""jqt" -- "nvd""#,
+        ] {
+            assert!(
+                dot.contains(&format!("{wire} [color=\"red\"")),
+                "{wire} is not cut"
+            );
+        }
+        assert_eq!(dot.matches("fillcolor").count(), 15);
+    }
+
+    #[test]
+    fn no_part_two() {
+        let input = Day25.parse(EXAMPLE).unwrap();
+        assert_eq!(Day25.part_two(&input).unwrap(), Answer::None);
+    }
+}
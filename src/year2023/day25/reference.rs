@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+/// tries cutting every three wires until the components fall apart, the product of the sizes of the two groups
+pub fn part_one(wires: &[(String, String)]) -> usize {
+    let mut names: HashMap<&str, usize> = HashMap::new();
+    for (a, b) in wires {
+        for name in [a, b] {
+            let next = names.len();
+            names.entry(name).or_insert(next);
+        }
+    }
+    // every component's neighbors, with the index of the wire to them
+    let mut neighbors: Vec<Vec<(usize, usize)>> = vec![vec![]; names.len()];
+    for (idx, (a, b)) in wires.iter().enumerate() {
+        neighbors[names[a.as_str()]].push((names[b.as_str()], idx));
+        neighbors[names[b.as_str()]].push((names[a.as_str()], idx));
+    }
+
+    let group_size = |cut: [usize; 3]| {
+        let mut seen = vec![false; names.len()];
+        seen[0] = true;
+        let mut stack = vec![0];
+        let mut size = 1;
+        while let Some(component) = stack.pop() {
+            for &(next, wire) in &neighbors[component] {
+                if !cut.contains(&wire) && !seen[next] {
+                    seen[next] = true;
+                    stack.push(next);
+                    size += 1;
+                }
+            }
+        }
+        size
+    };
+
+    for a in 0..wires.len() {
+        for b in a + 1..wires.len() {
+            for c in b + 1..wires.len() {
+                let size = group_size([a, b, c]);
+                if size < names.len() {
+                    return size * (names.len() - size);
+                }
+            }
+        }
+    }
+    panic!("no three wires split the components")
+}
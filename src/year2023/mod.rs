@@ -0,0 +1,147 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+// new days are inserted above this line by `aoc new-day`
+
+use crate::Registry;
+
+pub const YEAR: u16 = 2023;
+
+/// adds all implemented days of 2023 to the registry
+pub fn register(registry: &mut Registry) {
+    registry.insert((YEAR, 1), Box::new(day01::Day01));
+    registry.insert((YEAR, 2), Box::new(day02::Day02));
+    registry.insert((YEAR, 3), Box::new(day03::Day03));
+    registry.insert((YEAR, 4), Box::new(day04::Day04));
+    registry.insert((YEAR, 5), Box::new(day05::Day05));
+    registry.insert((YEAR, 6), Box::new(day06::Day06));
+    registry.insert((YEAR, 7), Box::new(day07::Day07));
+    registry.insert((YEAR, 8), Box::new(day08::Day08));
+    registry.insert((YEAR, 9), Box::new(day09::Day09));
+    registry.insert((YEAR, 10), Box::new(day10::Day10));
+    registry.insert((YEAR, 11), Box::new(day11::Day11));
+    registry.insert((YEAR, 12), Box::new(day12::Day12));
+    registry.insert((YEAR, 13), Box::new(day13::Day13));
+    registry.insert((YEAR, 14), Box::new(day14::Day14));
+    registry.insert((YEAR, 15), Box::new(day15::Day15));
+    registry.insert((YEAR, 16), Box::new(day16::Day16));
+    registry.insert((YEAR, 17), Box::new(day17::Day17));
+    registry.insert((YEAR, 18), Box::new(day18::Day18));
+    registry.insert((YEAR, 19), Box::new(day19::Day19));
+    registry.insert((YEAR, 20), Box::new(day20::Day20));
+    registry.insert((YEAR, 21), Box::new(day21::Day21));
+    registry.insert((YEAR, 22), Box::new(day22::Day22));
+    registry.insert((YEAR, 23), Box::new(day23::Day23));
+    registry.insert((YEAR, 24), Box::new(day24::Day24));
+    registry.insert((YEAR, 25), Box::new(day25::Day25));
+    // new days are inserted above this line by `aoc new-day`
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{check_parse, check_round_trip, generate};
+
+    fn solvers() -> Registry {
+        let mut registry = Registry::new();
+        register(&mut registry);
+        registry
+    }
+
+    /// text made of the characters the puzzle inputs use, which gets further into the parsers than any string
+    fn puzzle_text() -> impl Strategy<Value = String> {
+        proptest::string::string_regex(r"([0-9a-zA-Z#.?|/\\<>=%&{}:;,@~ -]{0,12}\n?){0,16}")
+            .unwrap()
+    }
+
+    /// valid input of a random day with a few characters replaced or removed
+    fn mutated_input() -> impl Strategy<Value = (u8, String)> {
+        let edits = proptest::collection::vec(
+            (any::<usize>(), proptest::option::of("[0-9a-z#.:, \n-]")),
+            1..4,
+        );
+        (1..=25u8, any::<u64>(), 1..6usize, edits).prop_map(|(day, seed, size, edits)| {
+            let solver = &solvers()[&(YEAR, day)];
+            let mut input: Vec<char> = solver
+                .generate(&mut generate::rng(seed), size)
+                .unwrap_or_default()
+                .chars()
+                .collect();
+            for (position, replacement) in edits {
+                if input.is_empty() {
+                    break;
+                }
+                let position = position % input.len();
+                let replacement: Vec<char> = replacement.unwrap_or_default().chars().collect();
+                input.splice(position..=position, replacement);
+            }
+            (day, input.into_iter().collect())
+        })
+    }
+
+    fn check_day(day: u8, input: &str) -> Result<(), TestCaseError> {
+        check_parse(solvers()[&(YEAR, day)].as_ref(), input)
+            .map_err(|err| TestCaseError::fail(format!("day {day}: {err}")))
+    }
+
+    proptest! {
+        #[test]
+        fn parsers_handle_any_string(input in any::<String>()) {
+            for day in 1..=25 {
+                check_day(day, &input)?;
+            }
+        }
+
+        #[test]
+        fn parsers_handle_puzzle_like_text(input in puzzle_text()) {
+            for day in 1..=25 {
+                check_day(day, &input)?;
+            }
+        }
+
+        #[test]
+        fn parsers_handle_broken_inputs((day, input) in mutated_input()) {
+            check_day(day, &input)?;
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn generated_inputs_round_trip(seed in any::<u64>(), size in 1..8usize) {
+            for (&(_, day), solver) in solvers().iter() {
+                let Some(input) = solver.generate(&mut generate::rng(seed), size) else {
+                    continue;
+                };
+                prop_assert!(solver.parse(&input).is_ok(), "day {day} can't parse its generated input");
+                check_round_trip(solver.as_ref(), &input)
+                    .map_err(|err| TestCaseError::fail(format!("day {day}: {err}")))?;
+            }
+        }
+    }
+}